@@ -1,14 +1,37 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
 
+use crate::storage::get_role_key;
+use crate::types::VotingSystemError;
 use crate::DataKey;
 
 pub mod traits;
 
+/// Roles allowed to modify parts of the contract state.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// The admin, upgrades the contract and grants the other roles
+    Owner,
+    /// Manages rounds, submissions, voting power calculations, tallies and budgets
+    RoundOperator,
+    /// Uploads votes and neuron results
+    DataUploader,
+    /// Manages layers, neurons and the voting power policy
+    Configurator,
+    /// Registers eligible voters of rounds
+    Attestor,
+}
+
 pub(crate) fn require_admin(env: &Env) {
     let admin = get_admin(env);
     admin.require_auth();
 }
 
+pub(crate) fn require_role(env: &Env, role: Role) {
+    let holder = get_role_holder(env, role);
+    holder.require_auth();
+}
+
 pub(crate) fn get_admin(env: &Env) -> Address {
     assert!(is_set_admin(env), "Admin not set");
     env.storage().instance().get(&DataKey::Admin).unwrap()
@@ -21,3 +44,46 @@ pub(crate) fn set_admin(env: &Env, admin: &Address) {
 pub(crate) fn is_set_admin(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::Admin)
 }
+
+/// Address proposed by `transfer_admin` that has not accepted the ownership yet.
+pub(crate) fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub(crate) fn set_pending_admin(env: &Env, pending_admin: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, pending_admin);
+}
+
+pub(crate) fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+/// Roles that were not granted to anyone are held by the owner.
+pub(crate) fn get_role_holder(env: &Env, role: Role) -> Address {
+    if role == Role::Owner {
+        return get_admin(env);
+    }
+    env.storage()
+        .instance()
+        .get(&get_role_key(role))
+        .unwrap_or_else(|| get_admin(env))
+}
+
+/// The owner is changed only with `transfer_admin`, it can't be granted or revoked like other roles.
+fn require_grantable(env: &Env, role: Role) {
+    if role == Role::Owner {
+        panic_with_error!(env, VotingSystemError::OwnerRoleNotGrantable);
+    }
+}
+
+pub(crate) fn set_role_holder(env: &Env, role: Role, holder: &Address) {
+    require_grantable(env, role);
+    env.storage().instance().set(&get_role_key(role), holder);
+}
+
+pub(crate) fn remove_role_holder(env: &Env, role: Role) {
+    require_grantable(env, role);
+    env.storage().instance().remove(&get_role_key(role));
+}
@@ -1,8 +1,29 @@
 use soroban_sdk::{Address, BytesN, Env};
 
+use crate::admin::Role;
+
 pub trait Admin {
-    /// Transfer ownership of the contract to `new_admin` address.
+    /// Propose transferring ownership of the contract to `new_admin` address.
+    ///
+    /// The ownership is transferred once `new_admin` calls `accept_admin`. Proposing another
+    /// address replaces the pending proposal.
     fn transfer_admin(env: Env, new_admin: Address);
+    /// Accept the ownership proposed by `transfer_admin`, authorized by the pending admin.
+    fn accept_admin(env: Env);
+    /// Cancel a pending ownership transfer.
+    fn cancel_admin_transfer(env: Env);
+    /// Get the address the ownership is being transferred to, if any.
+    fn get_pending_admin(env: Env) -> Option<Address>;
     /// Upgrade the implementation of the contract with one identified by `wasm_hash`.
     fn upgrade(env: Env, wasm_hash: BytesN<32>);
+    /// Grant `role` to `holder`, replacing its previous holder. The owner can only be changed with
+    /// `transfer_admin`, granting `Role::Owner` fails with `OwnerRoleNotGrantable`.
+    fn grant_role(env: Env, role: Role, holder: Address);
+    /// Revoke `role` from its holder, the owner holds the role again. Revoking `Role::Owner` fails
+    /// with `OwnerRoleNotGrantable`.
+    fn revoke_role(env: Env, role: Role);
+    /// Get the address holding `role`.
+    fn get_role_holder(env: Env, role: Role) -> Address;
+    /// Check whether `address` holds `role`.
+    fn has_role(env: Env, role: Role, address: Address) -> bool;
 }
@@ -0,0 +1,184 @@
+use soroban_sdk::{Env, Map, String, Vec, I256};
+
+use crate::fixed_mul_floor::mul_div_floor;
+use crate::types::{CategoryBudget, VotingSystemError};
+use crate::ContractResult;
+
+pub(crate) fn validate_budget(budget: &CategoryBudget) -> ContractResult<()> {
+    if budget.amount < 0 || budget.max_award.is_some_and(|max_award| max_award < 0) {
+        return Err(VotingSystemError::InvalidBudget);
+    }
+    Ok(())
+}
+
+/// Split the budget of a category between its submissions.
+///
+/// `ranking` has to be ordered by score, highest first. Submissions without a positive score, below
+/// `min_score` and outside of `top_n` are not awarded. The budget is split equally between the
+/// remaining submissions, or proportionally to their scores with `pro_rata`. Awards are capped at
+/// `max_award`; the capped amount stays unallocated.
+pub(crate) fn allocate_budget(
+    env: &Env,
+    budget: &CategoryBudget,
+    ranking: &Vec<(String, I256)>,
+) -> ContractResult<Map<String, i128>> {
+    let zero = I256::from_i32(env, 0);
+    let mut eligible: Vec<(String, I256)> = Vec::new(env);
+
+    for (submission_id, score) in ranking.iter() {
+        if budget.top_n.is_some_and(|top_n| eligible.len() >= top_n) {
+            break;
+        }
+        if budget
+            .min_score
+            .as_ref()
+            .is_some_and(|min_score| score < *min_score)
+        {
+            continue;
+        }
+        if score <= zero {
+            continue;
+        }
+        eligible.push_back((submission_id, score));
+    }
+
+    let mut allocations = Map::new(env);
+    if eligible.is_empty() {
+        return Ok(allocations);
+    }
+
+    let amount = I256::from_i128(env, budget.amount);
+    let total_score = eligible
+        .iter()
+        .fold(zero.clone(), |total, (_id, score)| total.add(&score));
+    let submissions_count = I256::from_i32(env, i32::try_from(eligible.len()).unwrap());
+
+    for (submission_id, score) in eligible.iter() {
+        let award = if budget.pro_rata {
+            mul_div_floor(env, &amount, &score, &total_score)
+        } else {
+            amount.div(&submissions_count)
+        };
+        let award = award.to_i128().ok_or(VotingSystemError::UnexpectedValue)?;
+        let award = match budget.max_award {
+            Some(max_award) => award.min(max_award),
+            None => award,
+        };
+        allocations.set(submission_id, award);
+    }
+
+    Ok(allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::vec;
+
+    fn ranking(env: &Env, scores: &[i32]) -> Vec<(String, I256)> {
+        let mut ranking = Vec::new(env);
+        for (i, score) in scores.iter().enumerate() {
+            ranking.push_back((
+                String::from_str(env, alloc::format!("sub{i}").as_str()),
+                I256::from_i32(env, *score),
+            ));
+        }
+        ranking
+    }
+
+    fn budget(amount: i128) -> CategoryBudget {
+        CategoryBudget {
+            amount,
+            top_n: None,
+            min_score: None,
+            max_award: None,
+            pro_rata: false,
+        }
+    }
+
+    #[test]
+    fn allocating_equally() {
+        let env = Env::default();
+
+        let allocations =
+            allocate_budget(&env, &budget(100), &ranking(&env, &[30, 20, 10, 0, -10])).unwrap();
+        assert_eq!(allocations.len(), 3);
+        assert_eq!(allocations.values(), vec![&env, 33_i128, 33_i128, 33_i128]);
+    }
+
+    #[test]
+    fn allocating_pro_rata() {
+        let env = Env::default();
+
+        let budget = CategoryBudget {
+            pro_rata: true,
+            ..budget(1000)
+        };
+        let allocations = allocate_budget(&env, &budget, &ranking(&env, &[60, 30, 10, 0])).unwrap();
+        assert_eq!(allocations.len(), 3);
+        assert_eq!(
+            allocations.get(String::from_str(&env, "sub0")),
+            Some(600_i128)
+        );
+        assert_eq!(
+            allocations.get(String::from_str(&env, "sub1")),
+            Some(300_i128)
+        );
+        assert_eq!(
+            allocations.get(String::from_str(&env, "sub2")),
+            Some(100_i128)
+        );
+    }
+
+    #[test]
+    fn allocating_with_rules() {
+        let env = Env::default();
+
+        let budget = CategoryBudget {
+            top_n: Some(2),
+            min_score: Some(I256::from_i32(&env, 15)),
+            max_award: Some(400),
+            pro_rata: true,
+            ..budget(1000)
+        };
+        let allocations = allocate_budget(&env, &budget, &ranking(&env, &[60, 20, 10, 5])).unwrap();
+        assert_eq!(allocations.len(), 2);
+        // 750 capped at 400
+        assert_eq!(
+            allocations.get(String::from_str(&env, "sub0")),
+            Some(400_i128)
+        );
+        assert_eq!(
+            allocations.get(String::from_str(&env, "sub1")),
+            Some(250_i128)
+        );
+    }
+
+    #[test]
+    fn allocating_without_eligible_submissions() {
+        let env = Env::default();
+
+        let budget = CategoryBudget {
+            pro_rata: true,
+            ..budget(1000)
+        };
+        let allocations = allocate_budget(&env, &budget, &ranking(&env, &[0, -5])).unwrap();
+        assert!(allocations.is_empty());
+    }
+
+    #[test]
+    fn validating_budget() {
+        assert_eq!(validate_budget(&budget(0)), Ok(()));
+        assert_eq!(
+            validate_budget(&budget(-1)),
+            Err(VotingSystemError::InvalidBudget)
+        );
+        assert_eq!(
+            validate_budget(&CategoryBudget {
+                max_award: Some(-1),
+                ..budget(10)
+            }),
+            Err(VotingSystemError::InvalidBudget)
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, BytesN, Env, Map, String};
+
+use crate::types::Vote;
+
+/// Hash a voter commits to in commit-reveal rounds: `sha256(xdr((round, voter, votes, salt)))`.
+///
+/// The round and the voter are part of the hash, so a commitment can't be copied by another voter
+/// or reused in a later round.
+pub(crate) fn vote_commitment(
+    env: &Env,
+    round: u32,
+    voter: &Address,
+    votes: &Map<String, Vote>,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let data = (round, voter.clone(), votes.clone(), salt.clone()).to_xdr(env);
+    env.crypto().sha256(&data).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn committing_votes() {
+        let env = Env::default();
+
+        let voter = Address::generate(&env);
+        let salt = BytesN::from_array(&env, &[7; 32]);
+        let mut votes = Map::new(&env);
+        votes.set(String::from_str(&env, "sub1"), Vote::Yes);
+        votes.set(String::from_str(&env, "sub2"), Vote::No);
+
+        let commitment = vote_commitment(&env, 25, &voter, &votes, &salt);
+        assert_eq!(commitment, vote_commitment(&env, 25, &voter, &votes, &salt));
+
+        // any change of the committed data changes the hash
+        assert_ne!(commitment, vote_commitment(&env, 26, &voter, &votes, &salt));
+        assert_ne!(
+            commitment,
+            vote_commitment(&env, 25, &Address::generate(&env), &votes, &salt)
+        );
+        assert_ne!(
+            commitment,
+            vote_commitment(
+                &env,
+                25,
+                &voter,
+                &votes,
+                &BytesN::from_array(&env, &[8; 32])
+            )
+        );
+        votes.set(String::from_str(&env, "sub2"), Vote::Yes);
+        assert_ne!(commitment, vote_commitment(&env, 25, &voter, &votes, &salt));
+    }
+}
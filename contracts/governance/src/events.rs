@@ -0,0 +1,863 @@
+use soroban_sdk::{contractevent, Address, BytesN, Env, String, Vec, I256};
+
+use crate::admin::Role;
+use crate::neural_governance::{LayerAggregator, LayerCombiner};
+use crate::types::{
+    CategoryBudget, RoundPhase, TallyResult, TallyRules, Vote, VotingMode, VotingPowerPolicy,
+};
+
+pub struct GovernanceEvents {}
+
+impl GovernanceEvents {
+    /// Emitted when the active round is changed
+    ///
+    /// - topics - `["round_set", round: u32]`
+    /// - data - ()
+    pub fn round_set(e: &Env, round: u32) {
+        RoundSet { round }.publish(e);
+    }
+
+    /// Emitted when a round moves to a new phase
+    ///
+    /// - topics - `["round_phase_changed", round: u32]`
+    /// - data - `phase: RoundPhase`
+    pub fn round_phase_changed(e: &Env, round: u32, phase: RoundPhase) {
+        RoundPhaseChanged { round, phase }.publish(e);
+    }
+
+    /// Emitted when submissions of a round are set
+    ///
+    /// - topics - `["submissions_set", round: u32]`
+    /// - data - `submissions_count: u32`
+    pub fn submissions_set(e: &Env, round: u32, submissions_count: u32) {
+        SubmissionsSet {
+            round,
+            submissions_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when submissions of a round stored as tuples are rewritten as typed submissions
+    ///
+    /// - topics - `["submissions_migrated", round: u32]`
+    /// - data - `submissions_count: u32`
+    pub fn submissions_migrated(e: &Env, round: u32, submissions_count: u32) {
+        SubmissionsMigrated {
+            round,
+            submissions_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when votes for a submission are uploaded by the admin
+    ///
+    /// - topics - `["votes_set", round: u32, submission_id: String]`
+    /// - data - `votes_count: u32`
+    pub fn votes_set(e: &Env, round: u32, submission_id: String, votes_count: u32) {
+        VotesSet {
+            round,
+            submission_id,
+            votes_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when eligible voters of a round are registered
+    ///
+    /// - topics - `["voters_registered", round: u32]`
+    /// - data - `eligible_voters_count: u32`
+    pub fn voters_registered(e: &Env, round: u32, eligible_voters_count: u32) {
+        VotersRegistered {
+            round,
+            eligible_voters_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when voters are removed from eligible voters of a round
+    ///
+    /// - topics - `["voters_unregistered", round: u32]`
+    /// - data - `eligible_voters_count: u32`
+    pub fn voters_unregistered(e: &Env, round: u32, eligible_voters_count: u32) {
+        VotersUnregistered {
+            round,
+            eligible_voters_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when votes for a submission signed by the voters are uploaded
+    ///
+    /// - topics - `["signed_votes_uploaded", round: u32, submission_id: String]`
+    /// - data - `votes_count: u32`
+    pub fn signed_votes_uploaded(e: &Env, round: u32, submission_id: String, votes_count: u32) {
+        SignedVotesUploaded {
+            round,
+            submission_id,
+            votes_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a chunk of votes for a submission is uploaded
+    ///
+    /// - topics - `["votes_chunk_appended", round: u32, submission_id: String]`
+    /// - data - `[chunk: u32, votes_count: u32]`
+    pub fn votes_chunk_appended(
+        e: &Env,
+        round: u32,
+        submission_id: String,
+        chunk: u32,
+        votes_count: u32,
+    ) {
+        VotesChunkAppended {
+            round,
+            submission_id,
+            chunk,
+            votes_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a chunked upload of votes for a submission is completed
+    ///
+    /// - topics - `["votes_sealed", round: u32, submission_id: String]`
+    /// - data - `chunks: u32`
+    pub fn votes_sealed(e: &Env, round: u32, submission_id: String, chunks: u32) {
+        VotesSealed {
+            round,
+            submission_id,
+            chunks,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a chunked upload of votes for a submission is discarded
+    ///
+    /// - topics - `["votes_upload_reset", round: u32, submission_id: String]`
+    /// - data - `chunks: u32`
+    pub fn votes_upload_reset(e: &Env, round: u32, submission_id: String, chunks: u32) {
+        VotesUploadReset {
+            round,
+            submission_id,
+            chunks,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a vote is cast by a voter
+    ///
+    /// - topics - `["vote_cast", round: u32, voter: Address]`
+    /// - data - `[submission_id: String, vote: Vote]`
+    pub fn vote_cast(e: &Env, round: u32, voter: Address, submission_id: String, vote: Vote) {
+        VoteCast {
+            round,
+            voter,
+            submission_id,
+            vote,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when the voting mode of a round is set
+    ///
+    /// - topics - `["voting_mode_set", round: u32]`
+    /// - data - `mode: VotingMode`
+    pub fn voting_mode_set(e: &Env, round: u32, mode: VotingMode) {
+        VotingModeSet { round, mode }.publish(e);
+    }
+
+    /// Emitted when a voter commits to their votes in a commit-reveal round
+    ///
+    /// - topics - `["votes_committed", round: u32, voter: Address]`
+    /// - data - `commitment: BytesN<32>`
+    pub fn votes_committed(e: &Env, round: u32, voter: Address, commitment: BytesN<32>) {
+        VotesCommitted {
+            round,
+            voter,
+            commitment,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a voter reveals the votes they committed to
+    ///
+    /// - topics - `["votes_revealed", round: u32, voter: Address]`
+    /// - data - `votes_count: u32`
+    pub fn votes_revealed(e: &Env, round: u32, voter: Address, votes_count: u32) {
+        VotesRevealed {
+            round,
+            voter,
+            votes_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a voter selects delegatees for a submission category
+    ///
+    /// - topics - `["delegatees_set", round: u32, voter: Address]`
+    /// - data - `[category: String, delegatees: Vec<String>]`
+    pub fn delegatees_set(
+        e: &Env,
+        round: u32,
+        voter: Address,
+        category: String,
+        delegatees: Vec<String>,
+    ) {
+        DelegateesSet {
+            round,
+            voter,
+            category,
+            delegatees,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a layer is added
+    ///
+    /// - topics - `["layer_added", layer_id: String]`
+    /// - data - `[aggregator: LayerAggregator, neurons: Vec<String>]`
+    pub fn layer_added(
+        e: &Env,
+        layer_id: String,
+        neurons: Vec<String>,
+        aggregator: LayerAggregator,
+    ) {
+        LayerAdded {
+            layer_id,
+            aggregator,
+            neurons,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a layer is updated
+    ///
+    /// - topics - `["layer_updated", layer_id: String]`
+    /// - data - `[aggregator: LayerAggregator, neurons: Vec<String>]`
+    pub fn layer_updated(
+        e: &Env,
+        layer_id: String,
+        neurons: Vec<String>,
+        aggregator: LayerAggregator,
+    ) {
+        LayerUpdated {
+            layer_id,
+            aggregator,
+            neurons,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a neuron is added to a layer
+    ///
+    /// - topics - `["neuron_added", layer_id: String, neuron_id: String]`
+    /// - data - `weight: I256`
+    pub fn neuron_added(e: &Env, layer_id: String, neuron_id: String, weight: I256) {
+        NeuronAdded {
+            layer_id,
+            neuron_id,
+            weight,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a neuron is removed from a layer
+    ///
+    /// - topics - `["neuron_removed", layer_id: String, neuron_id: String]`
+    /// - data - ()
+    pub fn neuron_removed(e: &Env, layer_id: String, neuron_id: String) {
+        NeuronRemoved {
+            layer_id,
+            neuron_id,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when inputs of a layer are set
+    ///
+    /// - topics - `["layer_inputs_set", layer_id: String]`
+    /// - data - `inputs: Vec<String>`
+    pub fn layer_inputs_set(e: &Env, layer_id: String, inputs: Vec<String>) {
+        LayerInputsSet { layer_id, inputs }.publish(e);
+    }
+
+    /// Emitted when a weight of a layer output is set
+    ///
+    /// - topics - `["layer_weight_set", layer_id: String]`
+    /// - data - `weight: I256`
+    pub fn layer_weight_set(e: &Env, layer_id: String, weight: I256) {
+        LayerWeightSet { layer_id, weight }.publish(e);
+    }
+
+    /// Emitted when a function combining outputs of the final layers is set
+    ///
+    /// - topics - `["layer_combiner_set"]`
+    /// - data - `combiner: LayerCombiner`
+    pub fn layer_combiner_set(e: &Env, combiner: LayerCombiner) {
+        LayerCombinerSet { combiner }.publish(e);
+    }
+
+    /// Emitted when a layer is removed
+    ///
+    /// - topics - `["layer_removed", layer_id: String]`
+    /// - data - ()
+    pub fn layer_removed(e: &Env, layer_id: String) {
+        LayerRemoved { layer_id }.publish(e);
+    }
+
+    /// Emitted when a neuron result is set
+    ///
+    /// - topics - `["neuron_result_set", round: u32, layer_id: String, neuron_id: String]`
+    /// - data - `voters_count: u32`
+    pub fn neuron_result_set(
+        e: &Env,
+        round: u32,
+        layer_id: String,
+        neuron_id: String,
+        voters_count: u32,
+    ) {
+        NeuronResultSet {
+            round,
+            layer_id,
+            neuron_id,
+            voters_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a chunk of a neuron result is uploaded
+    ///
+    /// - topics - `["neuron_result_chunk_appended", round: u32, layer_id: String, neuron_id: String]`
+    /// - data - `[chunk: u32, voters_count: u32]`
+    pub fn neuron_result_chunk_appended(
+        e: &Env,
+        round: u32,
+        layer_id: String,
+        neuron_id: String,
+        chunk: u32,
+        voters_count: u32,
+    ) {
+        NeuronResultChunkAppended {
+            round,
+            layer_id,
+            neuron_id,
+            chunk,
+            voters_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a chunked upload of a neuron result is completed
+    ///
+    /// - topics - `["neuron_result_sealed", round: u32, layer_id: String, neuron_id: String]`
+    /// - data - `chunks: u32`
+    pub fn neuron_result_sealed(
+        e: &Env,
+        round: u32,
+        layer_id: String,
+        neuron_id: String,
+        chunks: u32,
+    ) {
+        NeuronResultSealed {
+            round,
+            layer_id,
+            neuron_id,
+            chunks,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a chunked upload of a neuron result is discarded
+    ///
+    /// - topics - `["neuron_result_upload_reset", round: u32, layer_id: String, neuron_id: String]`
+    /// - data - `chunks: u32`
+    pub fn neuron_result_upload_reset(
+        e: &Env,
+        round: u32,
+        layer_id: String,
+        neuron_id: String,
+        chunks: u32,
+    ) {
+        NeuronResultUploadReset {
+            round,
+            layer_id,
+            neuron_id,
+            chunks,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a Merkle root of a neuron result is committed
+    ///
+    /// - topics - `["neuron_result_root_set", round: u32, layer_id: String, neuron_id: String]`
+    /// - data - `root: BytesN<32>`
+    pub fn neuron_result_root_set(
+        e: &Env,
+        round: u32,
+        layer_id: String,
+        neuron_id: String,
+        root: BytesN<32>,
+    ) {
+        NeuronResultRootSet {
+            round,
+            layer_id,
+            neuron_id,
+            root,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a voter claims their value of a committed neuron result
+    ///
+    /// - topics - `["voting_power_claimed", round: u32, voter: Address]`
+    /// - data - `[layer_id: String, neuron_id: String, value: I256]`
+    pub fn voting_power_claimed(
+        e: &Env,
+        round: u32,
+        voter: Address,
+        layer_id: String,
+        neuron_id: String,
+        value: I256,
+    ) {
+        VotingPowerClaimed {
+            round,
+            voter,
+            layer_id,
+            neuron_id,
+            value,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when the voting power policy is set
+    ///
+    /// - topics - `["voting_power_policy_set"]`
+    /// - data - `policy: VotingPowerPolicy`
+    pub fn voting_power_policy_set(e: &Env, policy: VotingPowerPolicy) {
+        VotingPowerPolicySet { policy }.publish(e);
+    }
+
+    /// Emitted when voting powers of a round are calculated
+    ///
+    /// - topics - `["voting_powers_calculated", round: u32]`
+    /// - data - `voters_count: u32`
+    pub fn voting_powers_calculated(e: &Env, round: u32, voters_count: u32) {
+        VotingPowersCalculated {
+            round,
+            voters_count,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a submission is tallied
+    ///
+    /// - topics - `["submission_tallied", round: u32, submission_id: String]`
+    /// - data - `result: TallyResult`
+    pub fn submission_tallied(e: &Env, round: u32, submission_id: String, result: TallyResult) {
+        SubmissionTallied {
+            round,
+            submission_id,
+            result,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when the tally rules of a round are set
+    ///
+    /// - topics - `["tally_rules_set", round: u32]`
+    /// - data - `rules: TallyRules`
+    pub fn tally_rules_set(e: &Env, round: u32, rules: TallyRules) {
+        TallyRulesSet { round, rules }.publish(e);
+    }
+
+    /// Emitted when the award budget of a category is set
+    ///
+    /// - topics - `["budget_set", round: u32, category: String]`
+    /// - data - `budget: CategoryBudget`
+    pub fn budget_set(e: &Env, round: u32, category: String, budget: CategoryBudget) {
+        BudgetSet {
+            round,
+            category,
+            budget,
+        }
+        .publish(e);
+    }
+
+    /// Emitted when a transfer of the admin is proposed
+    ///
+    /// - topics - `["admin_transfer_proposed", new_admin: Address]`
+    /// - data - ()
+    pub fn admin_transfer_proposed(e: &Env, new_admin: Address) {
+        AdminTransferProposed { new_admin }.publish(e);
+    }
+
+    /// Emitted when a pending transfer of the admin is cancelled
+    ///
+    /// - topics - `["admin_transfer_cancelled", pending_admin: Address]`
+    /// - data - ()
+    pub fn admin_transfer_cancelled(e: &Env, pending_admin: Address) {
+        AdminTransferCancelled { pending_admin }.publish(e);
+    }
+
+    /// Emitted when the proposed admin accepts the transfer
+    ///
+    /// - topics - `["admin_transferred", new_admin: Address]`
+    /// - data - ()
+    pub fn admin_transferred(e: &Env, new_admin: Address) {
+        AdminTransferred { new_admin }.publish(e);
+    }
+
+    /// Emitted when a role is granted
+    ///
+    /// - topics - `["role_granted", holder: Address]`
+    /// - data - `role: Role`
+    pub fn role_granted(e: &Env, role: Role, holder: Address) {
+        RoleGranted { holder, role }.publish(e);
+    }
+
+    /// Emitted when a role is revoked and returns to the owner
+    ///
+    /// - topics - `["role_revoked"]`
+    /// - data - `role: Role`
+    pub fn role_revoked(e: &Env, role: Role) {
+        RoleRevoked { role }.publish(e);
+    }
+
+    /// Emitted when the contract is upgraded
+    ///
+    /// - topics - `["upgraded"]`
+    /// - data - `wasm_hash: BytesN<32>`
+    pub fn upgraded(e: &Env, wasm_hash: BytesN<32>) {
+        Upgraded { wasm_hash }.publish(e);
+    }
+}
+
+#[contractevent(data_format = "single-value")]
+struct RoundSet {
+    #[topic]
+    round: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct RoundPhaseChanged {
+    #[topic]
+    round: u32,
+    phase: RoundPhase,
+}
+
+#[contractevent(data_format = "single-value")]
+struct SubmissionsSet {
+    #[topic]
+    round: u32,
+    submissions_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct SubmissionsMigrated {
+    #[topic]
+    round: u32,
+    submissions_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotesSet {
+    #[topic]
+    round: u32,
+    #[topic]
+    submission_id: String,
+    votes_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotersRegistered {
+    #[topic]
+    round: u32,
+    eligible_voters_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotersUnregistered {
+    #[topic]
+    round: u32,
+    eligible_voters_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct SignedVotesUploaded {
+    #[topic]
+    round: u32,
+    #[topic]
+    submission_id: String,
+    votes_count: u32,
+}
+
+#[contractevent(data_format = "vec")]
+struct VotesChunkAppended {
+    #[topic]
+    round: u32,
+    #[topic]
+    submission_id: String,
+    chunk: u32,
+    votes_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotesSealed {
+    #[topic]
+    round: u32,
+    #[topic]
+    submission_id: String,
+    chunks: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotesUploadReset {
+    #[topic]
+    round: u32,
+    #[topic]
+    submission_id: String,
+    chunks: u32,
+}
+
+#[contractevent(data_format = "vec")]
+struct VoteCast {
+    #[topic]
+    round: u32,
+    #[topic]
+    voter: Address,
+    submission_id: String,
+    vote: Vote,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotingModeSet {
+    #[topic]
+    round: u32,
+    mode: VotingMode,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotesCommitted {
+    #[topic]
+    round: u32,
+    #[topic]
+    voter: Address,
+    commitment: BytesN<32>,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotesRevealed {
+    #[topic]
+    round: u32,
+    #[topic]
+    voter: Address,
+    votes_count: u32,
+}
+
+#[contractevent(data_format = "vec")]
+struct DelegateesSet {
+    #[topic]
+    round: u32,
+    #[topic]
+    voter: Address,
+    category: String,
+    delegatees: Vec<String>,
+}
+
+#[contractevent(data_format = "vec")]
+struct LayerAdded {
+    #[topic]
+    layer_id: String,
+    aggregator: LayerAggregator,
+    neurons: Vec<String>,
+}
+
+#[contractevent(data_format = "vec")]
+struct LayerUpdated {
+    #[topic]
+    layer_id: String,
+    aggregator: LayerAggregator,
+    neurons: Vec<String>,
+}
+
+#[contractevent(data_format = "single-value")]
+struct NeuronAdded {
+    #[topic]
+    layer_id: String,
+    #[topic]
+    neuron_id: String,
+    weight: I256,
+}
+
+#[contractevent(data_format = "single-value")]
+struct NeuronRemoved {
+    #[topic]
+    layer_id: String,
+    #[topic]
+    neuron_id: String,
+}
+
+#[contractevent(data_format = "single-value")]
+struct LayerInputsSet {
+    #[topic]
+    layer_id: String,
+    inputs: Vec<String>,
+}
+
+#[contractevent(data_format = "single-value")]
+struct LayerWeightSet {
+    #[topic]
+    layer_id: String,
+    weight: I256,
+}
+
+#[contractevent(data_format = "single-value")]
+struct LayerCombinerSet {
+    combiner: LayerCombiner,
+}
+
+#[contractevent(data_format = "single-value")]
+struct LayerRemoved {
+    #[topic]
+    layer_id: String,
+}
+
+#[contractevent(data_format = "single-value")]
+struct NeuronResultSet {
+    #[topic]
+    round: u32,
+    #[topic]
+    layer_id: String,
+    #[topic]
+    neuron_id: String,
+    voters_count: u32,
+}
+
+#[contractevent(data_format = "vec")]
+struct NeuronResultChunkAppended {
+    #[topic]
+    round: u32,
+    #[topic]
+    layer_id: String,
+    #[topic]
+    neuron_id: String,
+    chunk: u32,
+    voters_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct NeuronResultSealed {
+    #[topic]
+    round: u32,
+    #[topic]
+    layer_id: String,
+    #[topic]
+    neuron_id: String,
+    chunks: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct NeuronResultUploadReset {
+    #[topic]
+    round: u32,
+    #[topic]
+    layer_id: String,
+    #[topic]
+    neuron_id: String,
+    chunks: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct NeuronResultRootSet {
+    #[topic]
+    round: u32,
+    #[topic]
+    layer_id: String,
+    #[topic]
+    neuron_id: String,
+    root: BytesN<32>,
+}
+
+#[contractevent(data_format = "vec")]
+struct VotingPowerClaimed {
+    #[topic]
+    round: u32,
+    #[topic]
+    voter: Address,
+    layer_id: String,
+    neuron_id: String,
+    value: I256,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotingPowerPolicySet {
+    policy: VotingPowerPolicy,
+}
+
+#[contractevent(data_format = "single-value")]
+struct VotingPowersCalculated {
+    #[topic]
+    round: u32,
+    voters_count: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+struct SubmissionTallied {
+    #[topic]
+    round: u32,
+    #[topic]
+    submission_id: String,
+    result: TallyResult,
+}
+
+#[contractevent(data_format = "single-value")]
+struct TallyRulesSet {
+    #[topic]
+    round: u32,
+    rules: TallyRules,
+}
+
+#[contractevent(data_format = "single-value")]
+struct BudgetSet {
+    #[topic]
+    round: u32,
+    #[topic]
+    category: String,
+    budget: CategoryBudget,
+}
+
+#[contractevent(data_format = "single-value")]
+struct AdminTransferProposed {
+    #[topic]
+    new_admin: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+struct AdminTransferCancelled {
+    #[topic]
+    pending_admin: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+struct AdminTransferred {
+    #[topic]
+    new_admin: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+struct RoleGranted {
+    #[topic]
+    holder: Address,
+    role: Role,
+}
+
+#[contractevent(data_format = "single-value")]
+struct RoleRevoked {
+    role: Role,
+}
+
+#[contractevent(data_format = "single-value")]
+struct Upgraded {
+    wasm_hash: BytesN<32>,
+}
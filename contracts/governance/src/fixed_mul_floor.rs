@@ -18,3 +18,29 @@ pub(crate) fn mul_div_floor(env: &Env, x: &I256, y: &I256, z: &I256) -> I256 {
         r.div(&z)
     }
 }
+
+/// Performs floor(sqrt(x * decimals)), the square root of a fixed-point number. Numbers that are
+/// not positive give zero.
+pub(crate) fn fixed_sqrt_floor(env: &Env, x: &I256, decimals: &I256) -> I256 {
+    let zero = I256::from_i32(env, 0);
+    if *x <= zero {
+        return zero;
+    }
+    let n = x.mul(decimals);
+    let two = I256::from_i32(env, 2);
+    let step = I256::from_i32(env, 256);
+
+    // grow the estimate above the root, then refine it with Newton's method, which approaches
+    // the floor of the root from above
+    let mut root = two.clone();
+    while root < n.div(&root) {
+        root = root.mul(&step);
+    }
+    loop {
+        let next = root.add(&n.div(&root)).div(&two);
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
@@ -5,33 +5,89 @@ extern crate alloc;
 
 use crate::fixed_mul_floor::fixed_mul_floor;
 use alloc::string::ToString;
+use soroban_sdk::xdr::ToXdr;
 // use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::{
-    contract, contractimpl, contracttype, vec, Address, BytesN, Env, Map, String, Vec, I256,
+    contract, contractimpl, contracttype, panic_with_error, Address, BytesN, Env, Map, String, Vec,
+    I256,
 };
 
-use admin::{is_set_admin, require_admin};
+use admin::{
+    get_pending_admin, get_role_holder, is_set_admin, remove_pending_admin, remove_role_holder,
+    require_admin, require_role, set_pending_admin, set_role_holder,
+};
 
 use crate::admin::set_admin;
 use crate::admin::traits::Admin;
+pub use crate::admin::Role;
+use crate::allocation::{allocate_budget, validate_budget};
+use crate::commitment::vote_commitment;
+use crate::events::GovernanceEvents;
+use crate::merkle::{leaf_hash, verify_proof};
 use crate::neural_governance::traits::Governance;
-pub use crate::neural_governance::LayerAggregator;
-use crate::neural_governance::{aggregate_result, Layer, Neuron, NGQ};
+use crate::neural_governance::{
+    aggregate_result, combine_results, evaluation_order, output_layers, Layer,
+    NeuralGovernanceRound, Neuron, NGQ,
+};
+pub use crate::neural_governance::{LayerAggregator, LayerCombiner};
+use crate::power_policy::{apply_policy, validate_policy};
+use crate::quorum::{require_delegatees, validate_delegatees};
+use crate::registry::{add_missing_voting_powers, require_eligible_voters, retain_eligible_voters};
+use crate::round::{require_phase, reset_voting_powers, set_phase};
+use crate::signature::account_address;
 use crate::storage::{
-    read_layer, read_neural_governance, read_neuron, read_neuron_result, read_submission_votes,
-    read_submissions, read_tally_results, read_voting_powers, remove_layer, remove_neuron,
-    write_layer, write_neural_governance, write_neuron, write_neuron_result,
-    write_submission_votes, write_submissions, write_tally_results, write_voting_powers,
-    LayerKeyData, NeuronKeyData, NeuronResultKeyData, SubmissionVotesKeyData, SubmissionsKeyData,
-    TallyResultsKeyData, VotingPowersKeyData,
+    add_submission_voter, extend_instance, extend_round, is_eligible_voter, put_neuron_result,
+    read_budgets, read_delegatees, read_layer, read_layer_inputs, read_neural_governance,
+    read_neural_governance_round, read_neuron, read_neuron_result_root, read_neuron_result_upload,
+    read_round_phase, read_submission_voters, read_submission_votes_upload, read_submissions,
+    read_tally_results, read_tally_rules, read_vote_commitment, read_vote_nonce,
+    read_voter_registry, read_voter_vote, read_voting_mode, read_voting_power_policy,
+    read_voting_powers, remove_eligible_voter, remove_layer, remove_layer_inputs, remove_neuron,
+    remove_neuron_result, remove_neuron_result_root, remove_neuron_result_upload,
+    remove_submission_votes_upload, remove_tally_results, remove_vote_commitment,
+    take_neuron_result, write_budgets, write_delegatees, write_eligible_voter, write_layer,
+    write_layer_inputs, write_neural_governance, write_neural_governance_round, write_neuron,
+    write_neuron_claim, write_neuron_result, write_neuron_result_root, write_submission_votes,
+    write_submissions, write_tally_results, write_tally_rules, write_vote_commitment,
+    write_vote_nonce, write_voter_registry, write_voter_vote, write_voting_mode,
+    write_voting_power_policy, write_voting_powers, BudgetsKeyData, DelegateesKeyData,
+    EligibleVoterKeyData, LayerKeyData, NeuralGovernanceRoundKeyData, NeuronClaimKeyData,
+    NeuronClaimantKeyData, NeuronClaimsKeyData, NeuronKeyData, NeuronResultChunkKeyData,
+    NeuronResultKeyData, RoleKeyData, RoundPhaseKeyData, SubmissionVoterKeyData,
+    SubmissionVotersKeyData, SubmissionVotesChunkKeyData, SubmissionVotesKeyData,
+    SubmissionsKeyData, TallyResultsKeyData, TallyRulesKeyData, VoteCommitmentKeyData,
+    VoteNonceKeyData, VoterRegistryKeyData, VoterVoteKeyData, VotingModeKeyData,
+    VotingPowersKeyData,
+};
+use crate::tally::{
+    count_yes_votes, rank_results, resolve_votes, tally_votes, validate_tally_rules,
+};
+use crate::types::{
+    CategoryBudget, LayerBreakdown, NeuronBreakdown, RoundPhase, SignedVote, Submission,
+    SubmissionCategory, TallyMode, TallyResult, TallyRules, Vote, VotingMode, VotingPowerBreakdown,
+    VotingPowerPolicy, VotingSystemError,
+};
+use crate::upload::{
+    append_neuron_result_chunk, append_votes_chunk, for_each_neuron_result_part,
+    load_neuron_result, load_submission_votes, seal_neuron_result, seal_votes,
 };
-use crate::types::{Vote, VotingSystemError, ABSTAIN_VOTING_POWER};
 
 mod admin;
+mod allocation;
+mod commitment;
+mod events;
 mod fixed_mul_floor;
+mod merkle;
 mod neural_governance;
+mod power_policy;
+mod quorum;
+mod registry;
+mod round;
+mod signature;
 mod storage;
+mod tally;
 pub mod types;
+mod upload;
 
 pub const DECIMALS: i128 = 1_000_000_000_000_000_000;
 
@@ -48,9 +104,9 @@ pub enum DataKey {
     /// storage type: instance
     /// Map<String, ()>
     Submissions(SubmissionsKeyData),
-    /// storage type: instance
-    /// Map<user_id, Vec<user_id>> - users to the vector of users they delegated their votes to
-    Delegatees,
+    /// storage type: persistent
+    /// Vec<user_id> - users the voter delegated their votes to within a submission category
+    Delegatees(DelegateesKeyData),
     // storage type: instance
     // Map<UserUUID, u32> - users to their delegation rank
     DelegationRanks,
@@ -61,12 +117,87 @@ pub enum DataKey {
     /// u32
     CurrentRound,
     NeuronKey(NeuronKeyData),
+    /// storage type: persistent
+    /// Map<user_id, I256>
     NeuronResultKey(NeuronResultKeyData),
     LayerKey(LayerKeyData),
+    /// storage type: instance
+    /// Vec<layer_id> - layers whose outputs are inputs of the layer
+    LayerInputs(LayerKeyData),
     SubmissionVotes(SubmissionVotesKeyData),
     VotingPowers(VotingPowersKeyData),
 
     TallyResults(TallyResultsKeyData),
+    /// storage type: persistent
+    /// RoundPhase
+    RoundPhase(RoundPhaseKeyData),
+    /// storage type: persistent
+    /// Map<category, CategoryBudget>
+    Budgets(BudgetsKeyData),
+    /// storage type: persistent
+    /// Vote - a vote signed by the voter
+    VoterVote(VoterVoteKeyData),
+    /// storage type: persistent
+    /// BytesN<32> - Merkle root of the neuron result, values are claimed with inclusion proofs
+    NeuronResultRoot(NeuronResultKeyData),
+    /// storage type: persistent
+    /// ChunkedUpload - state of a neuron result uploaded in chunks
+    NeuronResultUpload(NeuronResultKeyData),
+    /// storage type: persistent
+    /// Map<user_id, I256> - a single chunk of a neuron result
+    NeuronResultChunk(NeuronResultChunkKeyData),
+    /// storage type: persistent
+    /// ChunkedUpload - state of submission votes uploaded in chunks
+    SubmissionVotesUpload(SubmissionVotesKeyData),
+    /// storage type: persistent
+    /// Map<user_id, Vote> - a single chunk of submission votes
+    SubmissionVotesChunk(SubmissionVotesChunkKeyData),
+    /// storage type: instance
+    /// VotingPowerPolicy - caps, floor and normalization applied to calculated voting powers
+    VotingPowerPolicy,
+    /// storage type: persistent
+    /// NeuralGovernanceRound - configuration used to calculate voting powers of the round
+    NeuralGovernanceRound(NeuralGovernanceRoundKeyData),
+    /// storage type: instance
+    /// Address - holder of a role other than the owner
+    Role(RoleKeyData),
+    /// storage type: instance
+    /// Address - proposed owner that has to accept the ownership
+    PendingAdmin,
+    /// storage type: persistent
+    /// VotingMode
+    VotingMode(VotingModeKeyData),
+    /// storage type: persistent
+    /// BytesN<32> - hash of the votes the voter committed to, removed once they are revealed
+    VoteCommitment(VoteCommitmentKeyData),
+    /// storage type: persistent
+    /// u64 - nonce of the last signed vote of the voter for the submission
+    VoteNonce(VoteNonceKeyData),
+    /// storage type: persistent
+    /// TallyRules - quorum and `No` vote weighting used to tally submissions of the round
+    TallyRules(TallyRulesKeyData),
+    /// storage type: persistent
+    /// u32 - number of eligible voters of the round, set once voters of the round are registered
+    VoterRegistry(VoterRegistryKeyData),
+    /// storage type: persistent
+    /// bool - the voter is registered as eligible in the round
+    EligibleVoter(EligibleVoterKeyData),
+    /// storage type: persistent
+    /// u32 - number of voters who cast a vote for the submission
+    SubmissionVotersCount(SubmissionVotersKeyData),
+    /// storage type: persistent
+    /// Address - voter who cast a vote for the submission, indexed in the order of their first vote
+    SubmissionVoter(SubmissionVoterKeyData),
+    /// storage type: persistent
+    /// u32 - number of voters who claimed a value of the neuron result under the Merkle root
+    NeuronClaimantsCount(NeuronClaimsKeyData),
+    /// storage type: persistent
+    /// Address - voter who claimed a value of the neuron result under the Merkle root, indexed in
+    /// the order of their first claim
+    NeuronClaimant(NeuronClaimantKeyData),
+    /// storage type: persistent
+    /// I256 - value of the neuron result claimed by the voter under the Merkle root
+    NeuronClaim(NeuronClaimKeyData),
 }
 
 #[contractimpl]
@@ -81,6 +212,7 @@ impl VotingSystem {
             .instance()
             .set(&DataKey::CurrentRound, &current_round);
         write_neural_governance(&env, neural_governance);
+        extend_instance(&env);
     }
 
     /// Get the current active round.
@@ -92,20 +224,44 @@ impl VotingSystem {
     }
 
     /// Change the active round.
+    ///
+    /// Data of each round is guarded by its own phase, so switching rounds never unlocks a
+    /// finalized round.
     pub fn set_current_round(env: Env, round: u32) {
-        require_admin(&env);
+        require_role(&env, Role::RoundOperator);
 
         env.storage().instance().set(&DataKey::CurrentRound, &round);
+        extend_instance(&env);
+        GovernanceEvents::round_set(&env, round);
     }
 
-    /// Set multiple submissions.
-    pub fn set_submissions(env: Env, new_submissions_raw: Vec<(String, String)>) {
-        let mut new_submissions = vec![&env];
-        for (name, category) in new_submissions_raw {
-            new_submissions.push_back((name, category));
-        }
+    /// Get the lifecycle phase of a round.
+    pub fn get_round_phase(env: &Env, round: u32) -> RoundPhase {
+        read_round_phase(env, round)
+    }
 
-        require_admin(&env);
+    /// Finalize the active round, making its submissions, votes, voting powers and tallies read-only.
+    ///
+    /// Requires the round to be tallied.
+    pub fn finalize_round(env: Env) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        require_phase(&env, round, &[RoundPhase::Tallied])?;
+        set_phase(&env, round, RoundPhase::Finalized);
+
+        Ok(())
+    }
+
+    /// Set multiple submissions.
+    ///
+    /// Submissions can be changed only during the `Setup` phase.
+    pub fn set_submissions(
+        env: Env,
+        new_submissions: Vec<Submission>,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+        require_phase(&env, Self::get_current_round(&env), &[RoundPhase::Setup])?;
 
         let mut submissions = Vec::new(&env);
 
@@ -116,41 +272,481 @@ impl VotingSystem {
             submissions.push_back(submission);
         }
 
-        write_submissions(&env, Self::get_current_round(&env), &submissions);
+        let round = Self::get_current_round(&env);
+        write_submissions(&env, round, &submissions);
+        GovernanceEvents::submissions_set(&env, round, submissions.len());
+        Ok(())
     }
 
     /// Get submissions for the active round.
-    pub fn get_submissions(env: &Env) -> Vec<(String, String)> {
+    pub fn get_submissions(env: &Env) -> Result<Vec<Submission>, VotingSystemError> {
         read_submissions(env, Self::get_current_round(env))
     }
 
+    /// Rewrite submissions of a round stored as `(id, category)` tuples as typed submissions.
+    ///
+    /// Tuples are also converted whenever they are read, migrating a round only saves converting
+    /// them again. Fails if a stored category isn't a known `SubmissionCategory`.
+    ///
+    /// Returns the number of submissions of the round.
+    pub fn migrate_submissions(env: Env, round: u32) -> Result<u32, VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let submissions = read_submissions(&env, round)?;
+        write_submissions(&env, round, &submissions);
+        GovernanceEvents::submissions_migrated(&env, round, submissions.len());
+        Ok(submissions.len())
+    }
+
+    /// Register eligible voters of the active round.
+    ///
+    /// Once voters of a round are registered, votes and neuron results of voters that are not
+    /// registered are rejected with `VoterNotEligible` when they are uploaded or cast. Rounds
+    /// without registered voters accept every voter. The registry can be changed until the round is
+    /// tallied, data uploaded before isn't affected. Registered voters without neuron results are
+    /// tallied with zero voting power.
+    ///
+    /// Returns the number of eligible voters of the round.
+    pub fn register_voters(env: Env, voters: Vec<String>) -> Result<u32, VotingSystemError> {
+        require_role(&env, Role::Attestor);
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+
+        let mut voters_count = read_voter_registry(&env, round).unwrap_or(0);
+        for voter_id in voters.iter() {
+            if !is_eligible_voter(&env, round, &voter_id) {
+                write_eligible_voter(&env, round, &voter_id);
+                voters_count += 1;
+            }
+        }
+        write_voter_registry(&env, round, voters_count);
+        remove_tally_results(&env, round);
+        GovernanceEvents::voters_registered(&env, round, voters_count);
+
+        Ok(voters_count)
+    }
+
+    /// Remove voters from the eligible voters of the active round.
+    ///
+    /// The round keeps its voter registry even if all voters are removed.
+    ///
+    /// Returns the number of eligible voters of the round.
+    pub fn unregister_voters(env: Env, voters: Vec<String>) -> Result<u32, VotingSystemError> {
+        require_role(&env, Role::Attestor);
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+
+        let mut voters_count = read_voter_registry(&env, round).unwrap_or(0);
+        for voter_id in voters.iter() {
+            if is_eligible_voter(&env, round, &voter_id) {
+                remove_eligible_voter(&env, round, &voter_id);
+                voters_count -= 1;
+            }
+        }
+        write_voter_registry(&env, round, voters_count);
+        remove_tally_results(&env, round);
+        GovernanceEvents::voters_unregistered(&env, round, voters_count);
+
+        Ok(voters_count)
+    }
+
+    /// Get the number of eligible voters of a specific round, `None` if the round has no voter
+    /// registry.
+    pub fn get_eligible_voters_count(env: &Env, round: u32) -> Option<u32> {
+        read_voter_registry(env, round)
+    }
+
+    /// Check whether a voter can vote and get voting power in a specific round.
+    pub fn is_eligible_voter(env: &Env, round: u32, voter_id: String) -> bool {
+        require_eligible_voters(env, round, &Vec::from_array(env, [voter_id])).is_ok()
+    }
+
     /// Set votes for a submission.
+    ///
+    /// Votes can be uploaded during the `Setup` and `Voting` phases. `Delegate` votes require
+    /// delegatees to be set for the submission category.
     pub fn set_votes_for_submission(
         env: &Env,
         submission_id: String,
         votes: Map<String, Vote>,
     ) -> Result<(), VotingSystemError> {
-        require_admin(env);
+        require_role(env, Role::DataUploader);
+        require_phase(
+            env,
+            Self::get_current_round(env),
+            &[RoundPhase::Setup, RoundPhase::Voting],
+        )?;
+
+        let round = Self::get_current_round(env);
+        require_voting_mode(env, round, VotingMode::Open)?;
+        let category = read_submission_category(env, round, &submission_id)?;
+        require_eligible_voters(env, round, &votes.keys())?;
+        require_delegatees(env, round, &category.name(env), &votes)?;
 
-        if !read_submissions(env, Self::get_current_round(env))
-            .iter()
-            .any(|(name, _category)| name == submission_id)
-        {
-            return Err(VotingSystemError::SubmissionDoesNotExist);
+        // this causes timeout god knows why
+        write_submission_votes(env, &submission_id, round, &votes);
+        remove_submission_votes_upload(env, &submission_id, round);
+        GovernanceEvents::votes_set(env, round, submission_id, votes.len());
+        Ok(())
+    }
+
+    /// Upload votes for a submission signed off-chain by the voters.
+    ///
+    /// Each vote carries an ed25519 signature of the voter over
+    /// `xdr((contract, round, submission_id, vote, nonce))`. Verified votes are recorded as votes of
+    /// the voter's account, the same way as votes cast with `cast_vote`. The whole batch is rejected
+    /// with `VoteNonceUsed` if a nonce isn't higher than the nonce of the previous signed vote of
+    /// the voter for the submission, so signed votes can't be replayed.
+    ///
+    /// Signatures are verified by the host, which can't report an invalid signature to the
+    /// contract: the invocation traps with a `Crypto` host error and none of the votes are
+    /// recorded.
+    pub fn upload_signed_votes(
+        env: &Env,
+        submission_id: String,
+        votes: Vec<SignedVote>,
+    ) -> Result<(), VotingSystemError> {
+        require_role(env, Role::DataUploader);
+        let round = Self::get_current_round(env);
+        require_phase(env, round, &[RoundPhase::Setup, RoundPhase::Voting])?;
+        require_voting_mode(env, round, VotingMode::Open)?;
+
+        for signed_vote in votes.iter() {
+            let voter = account_address(env, &signed_vote.public_key);
+            if read_vote_nonce(env, &submission_id, round, &voter)
+                .is_some_and(|last_nonce| signed_vote.nonce <= last_nonce)
+            {
+                return Err(VotingSystemError::VoteNonceUsed);
+            }
+
+            let message = (
+                env.current_contract_address(),
+                round,
+                submission_id.clone(),
+                signed_vote.vote,
+                signed_vote.nonce,
+            )
+                .to_xdr(env);
+            env.crypto()
+                .ed25519_verify(&signed_vote.public_key, &message, &signed_vote.signature);
+
+            record_vote(env, round, &voter, &submission_id, signed_vote.vote)?;
+            write_vote_nonce(env, &submission_id, round, &voter, signed_vote.nonce);
         }
+        GovernanceEvents::signed_votes_uploaded(env, round, submission_id, votes.len());
+
+        Ok(())
+    }
+
+    /// Get the nonce of the last signed vote of a voter for a submission in a specific round.
+    pub fn get_vote_nonce(
+        env: &Env,
+        round: u32,
+        submission_id: String,
+        voter: Address,
+    ) -> Option<u64> {
+        read_vote_nonce(env, &submission_id, round, &voter)
+    }
+
+    /// Upload another chunk of votes for a submission.
+    ///
+    /// Rounds with too many votes to upload with `set_votes_for_submission` at once can upload them
+    /// in chunks. Each chunk is stored separately and the votes are used only after `seal_votes` is
+    /// called. A sealed upload can be replaced with `set_votes_for_submission` or discarded with
+    /// `reset_votes_upload`.
+    ///
+    /// Returns the number of chunks uploaded so far.
+    pub fn append_votes_chunk(
+        env: &Env,
+        submission_id: String,
+        votes: Map<String, Vote>,
+    ) -> Result<u32, VotingSystemError> {
+        require_role(env, Role::DataUploader);
+        let round = Self::get_current_round(env);
+        require_phase(env, round, &[RoundPhase::Setup, RoundPhase::Voting])?;
+        require_voting_mode(env, round, VotingMode::Open)?;
+        let category = read_submission_category(env, round, &submission_id)?;
+        require_eligible_voters(env, round, &votes.keys())?;
+        require_delegatees(env, round, &category.name(env), &votes)?;
+
+        let upload = append_votes_chunk(env, &submission_id, round, &votes)?;
+        GovernanceEvents::votes_chunk_appended(
+            env,
+            round,
+            submission_id,
+            upload.chunks - 1,
+            votes.len(),
+        );
+        Ok(upload.chunks)
+    }
+
+    /// Complete a chunked upload of votes for a submission.
+    pub fn seal_votes(env: &Env, submission_id: String) -> Result<(), VotingSystemError> {
+        require_role(env, Role::DataUploader);
+        let round = Self::get_current_round(env);
+        require_phase(env, round, &[RoundPhase::Setup, RoundPhase::Voting])?;
+
+        let upload = seal_votes(env, &submission_id, round)?;
+        GovernanceEvents::votes_sealed(env, round, submission_id, upload.chunks);
+        Ok(())
+    }
+
+    /// Discard a chunked upload of votes for a submission, sealed or not, so it can be uploaded
+    /// again from the first chunk.
+    pub fn reset_votes_upload(env: &Env, submission_id: String) -> Result<(), VotingSystemError> {
+        require_role(env, Role::DataUploader);
+        let round = Self::get_current_round(env);
+        require_phase(env, round, &[RoundPhase::Setup, RoundPhase::Voting])?;
+
+        let upload = read_submission_votes_upload(env, &submission_id, round)
+            .ok_or(VotingSystemError::VotesForSubmissionNotSet)?;
+        remove_submission_votes_upload(env, &submission_id, round);
+        GovernanceEvents::votes_upload_reset(env, round, submission_id, upload.chunks);
+        Ok(())
+    }
+
+    /// Open the voting window for the active round, moving it from `Setup` to `Voting`.
+    ///
+    /// The window closes when voting powers are calculated.
+    pub fn open_voting(env: Env) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        require_phase(&env, round, &[RoundPhase::Setup])?;
+        set_phase(&env, round, RoundPhase::Voting);
+
+        Ok(())
+    }
+
+    /// Close the voting window of the active round.
+    ///
+    /// Closes the `Voting` phase in open rounds and the `Reveal` phase in commit-reveal rounds, after
+    /// which votes can't be cast or revealed anymore.
+    pub fn close_voting(env: Env) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        let voting_phase = match read_voting_mode(&env, round) {
+            VotingMode::Open => RoundPhase::Voting,
+            VotingMode::CommitReveal => RoundPhase::Reveal,
+        };
+        require_phase(&env, round, &[voting_phase])?;
+        set_phase(&env, round, RoundPhase::VotingClosed);
+
+        Ok(())
+    }
+
+    /// Check whether voters can cast votes in the active round.
+    pub fn is_voting_open(env: &Env) -> bool {
+        read_round_phase(env, Self::get_current_round(env)) == RoundPhase::Voting
+    }
+
+    /// Cast a vote for a submission in the active round.
+    ///
+    /// The vote can be changed by casting it again as long as the voting window is open.
+    /// Votes cast by voters take precedence over votes uploaded with `set_votes_for_submission`.
+    /// Casting a `Delegate` vote requires delegatees to be set for the submission category.
+    ///
+    /// Votes can't be cast in commit-reveal rounds, see `commit_votes`.
+    pub fn cast_vote(
+        env: Env,
+        voter: Address,
+        submission_id: String,
+        vote: Vote,
+    ) -> Result<(), VotingSystemError> {
+        voter.require_auth();
+
+        let round = Self::get_current_round(&env);
+        if read_round_phase(&env, round) != RoundPhase::Voting {
+            return Err(VotingSystemError::VotingClosed);
+        }
+        require_voting_mode(&env, round, VotingMode::Open)?;
+
+        record_vote(&env, round, &voter, &submission_id, vote)?;
+        GovernanceEvents::vote_cast(&env, round, voter, submission_id, vote);
+
+        Ok(())
+    }
+
+    /// Set how voters vote in the active round.
+    ///
+    /// The voting mode can be changed only during the `Setup` phase.
+    pub fn set_voting_mode(env: Env, mode: VotingMode) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        require_phase(&env, round, &[RoundPhase::Setup])?;
+        write_voting_mode(&env, round, mode);
+        GovernanceEvents::voting_mode_set(&env, round, mode);
+
+        Ok(())
+    }
+
+    /// Get how voters vote in a specific round.
+    pub fn get_voting_mode(env: &Env, round: u32) -> VotingMode {
+        read_voting_mode(env, round)
+    }
+
+    /// Commit to votes in a commit-reveal round.
+    ///
+    /// Voters commit while the voting window is open and can replace their commitment until it
+    /// closes with `open_reveal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `commitment`: `sha256(xdr((round, voter, votes, salt)))`, where `votes` is a
+    ///   `Map<String, Vote>` of submission IDs to votes and `salt` is a random `BytesN<32>` kept
+    ///   secret until the votes are revealed.
+    pub fn commit_votes(
+        env: Env,
+        voter: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), VotingSystemError> {
+        voter.require_auth();
+
+        let round = Self::get_current_round(&env);
+        if read_round_phase(&env, round) != RoundPhase::Voting {
+            return Err(VotingSystemError::VotingClosed);
+        }
+        require_voting_mode(&env, round, VotingMode::CommitReveal)?;
+        require_eligible_voters(&env, round, &Vec::from_array(&env, [voter.to_string()]))?;
+
+        write_vote_commitment(&env, round, &voter, &commitment);
+        GovernanceEvents::votes_committed(&env, round, voter, commitment);
+
+        Ok(())
+    }
+
+    /// Get the unrevealed commitment of a voter in a specific round.
+    pub fn get_vote_commitment(env: &Env, round: u32, voter: Address) -> Option<BytesN<32>> {
+        read_vote_commitment(env, round, &voter)
+    }
+
+    /// Close the commit window of a commit-reveal round, moving it from `Voting` to `Reveal`.
+    pub fn open_reveal(env: Env) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        require_phase(&env, round, &[RoundPhase::Voting])?;
+        require_voting_mode(&env, round, VotingMode::CommitReveal)?;
+        set_phase(&env, round, RoundPhase::Reveal);
+
+        Ok(())
+    }
+
+    /// Reveal the votes a voter committed to with `commit_votes`.
+    ///
+    /// The votes and the salt have to match the commitment. Revealed votes are recorded the same
+    /// way as cast votes, votes that are never revealed are not tallied. Votes can be revealed
+    /// until the voting is closed or voting powers are calculated.
+    pub fn reveal_votes(
+        env: Env,
+        voter: Address,
+        votes: Map<String, Vote>,
+        salt: BytesN<32>,
+    ) -> Result<(), VotingSystemError> {
+        voter.require_auth();
+
+        let round = Self::get_current_round(&env);
+        require_phase(&env, round, &[RoundPhase::Reveal])?;
+
+        let commitment = read_vote_commitment(&env, round, &voter)
+            .ok_or(VotingSystemError::VoteCommitmentNotSet)?;
+        if vote_commitment(&env, round, &voter, &votes, &salt) != commitment {
+            return Err(VotingSystemError::InvalidVoteCommitment);
+        }
+
+        for (submission_id, vote) in votes.iter() {
+            record_vote(&env, round, &voter, &submission_id, vote)?;
+        }
+        remove_vote_commitment(&env, round, &voter);
+        GovernanceEvents::votes_revealed(&env, round, voter, votes.len());
 
-        // this causes timeout god knows why
-        write_submission_votes(env, &submission_id, Self::get_current_round(env), &votes);
         Ok(())
     }
 
+    /// Select delegatees of the voter for a submission category in the active round.
+    ///
+    /// `Delegate` votes of the voter are resolved from the delegatees selected in the same round
+    /// when tallying. Delegatees are selected for each round separately and can be changed only
+    /// during the `Setup` and `Voting` phases of the active round, so votes that were already cast
+    /// can't be redirected once the voting closes, not even from a later round.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: name of a `SubmissionCategory`
+    pub fn set_delegatees(
+        env: Env,
+        voter: Address,
+        category: String,
+        delegatees: Vec<String>,
+    ) -> Result<(), VotingSystemError> {
+        voter.require_auth();
+        validate_category(&env, &category)?;
+        let round = Self::get_current_round(&env);
+        require_phase(&env, round, &[RoundPhase::Setup, RoundPhase::Voting])?;
+
+        let voter_id = voter.to_string();
+        validate_delegatees(&voter_id, &delegatees)?;
+        write_delegatees(&env, &voter_id, &category, round, &delegatees);
+        GovernanceEvents::delegatees_set(&env, round, voter, category, delegatees);
+
+        Ok(())
+    }
+
+    /// Get delegatees of the voter for a submission category in a specific round.
+    pub fn get_delegatees(
+        env: &Env,
+        round: u32,
+        voter: Address,
+        category: String,
+    ) -> Result<Vec<String>, VotingSystemError> {
+        read_delegatees(env, &voter.to_string(), &category, round)
+    }
+
     /// Get votes for the submission for a specific round.
+    ///
+    /// Includes both uploaded votes and votes cast by voters themselves.
     pub fn get_votes_for_submission_round(
         env: &Env,
         submission_id: String,
         round: u32,
     ) -> Result<Map<String, Vote>, VotingSystemError> {
-        read_submission_votes(env, &submission_id, round)
+        let voters = read_submission_voters(env, &submission_id, round);
+        let mut votes = Map::new(env);
+        match load_submission_votes(env, &submission_id, round, &mut votes) {
+            Ok(()) => {}
+            Err(VotingSystemError::VotesForSubmissionNotSet) if !voters.is_empty() => {}
+            Err(err) => return Err(err),
+        }
+
+        for voter in voters {
+            if let Some(vote) = read_voter_vote(env, &submission_id, round, &voter) {
+                votes.set(voter.to_string(), vote);
+            }
+        }
+
+        Ok(votes)
     }
 
     /// Get votes for the submission for the active round
@@ -161,55 +757,282 @@ impl VotingSystem {
         Self::get_votes_for_submission_round(env, submission_id, Self::get_current_round(env))
     }
 
-    /// Compute the final voting power of a submission.
+    /// Set the rules for tallying submissions of the active round.
+    ///
+    /// The rules can be changed until the tally is finalized.
+    pub fn set_tally_rules(env: Env, rules: TallyRules) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+        validate_tally_rules(&env, &rules)?;
+        write_tally_rules(&env, round, &rules);
+        remove_tally_results(&env, round);
+        GovernanceEvents::tally_rules_set(&env, round, rules);
+
+        Ok(())
+    }
+
+    /// Get the rules for tallying submissions of a specific round.
+    pub fn get_tally_rules(env: &Env, round: u32) -> TallyRules {
+        read_tally_rules(env, round)
+    }
+
+    /// Compute the final voting power of a submission and whether it passed.
     ///
     /// Requires calling `calculate_voting_powers` first to compute and store voting powers for the round.
+    /// `Delegate` votes are resolved using the quorum of the voter for the submission category.
+    /// The submission passes when its score is positive and it reaches the quorum set in the tally
+    /// rules of the round.
+    /// Anyone can tally once voting powers are calculated. Stored results are provisional until the
+    /// round operator calls `finalize_tally`, and are dropped when voting powers, tally rules or
+    /// eligible voters change.
     ///
-    /// # Panics:
-    ///
-    /// The function will panic if no voting powers are set for the active round.
-    pub fn tally_submission(env: &Env, submission_id: String) -> Result<I256, VotingSystemError> {
-        let submission_votes = Self::get_votes_for_submission(env, submission_id.clone())?;
-        let mut submission_voting_power_plus = I256::from_i32(env, 0);
-        let mut submission_voting_power_minus = I256::from_i32(env, 0);
-        let voting_powers = Self::get_voting_powers(env.clone())?;
-
-        for (voter_id, vote) in submission_votes {
-            let voting_power = match vote {
-                Vote::Abstain => I256::from_i32(env, ABSTAIN_VOTING_POWER),
-                _ => voting_powers
-                    .get(voter_id)
-                    .ok_or(VotingSystemError::NGQResultForVoterMissing)?,
-            };
-            match vote {
-                Vote::Yes => {
-                    submission_voting_power_plus = submission_voting_power_plus.add(&voting_power);
-                }
-                Vote::No => {
-                    submission_voting_power_minus =
-                        submission_voting_power_minus.add(&voting_power);
-                }
-                Vote::Abstain => (),
+    /// # Errors:
+    ///
+    /// Fails with `InvalidRoundPhase` if voting powers are not calculated for the active round or
+    /// the tally is finalized.
+    pub fn tally_submission(
+        env: &Env,
+        submission_id: String,
+    ) -> Result<TallyResult, VotingSystemError> {
+        let round = Self::get_current_round(env);
+        require_phase(env, round, &[RoundPhase::PowersCalculated])?;
+
+        let voting_powers = read_voting_powers(env, round, true)?;
+        let tally_result = tally_submission_votes(env, round, &submission_id, &voting_powers)?;
+
+        let mut tally_results: Map<String, TallyResult> = match read_tally_results(env, round) {
+            Ok(tally_results) => tally_results,
+            Err(_) => Map::new(env),
+        };
+        tally_results.set(submission_id.clone(), tally_result.clone());
+        write_tally_results(env, round, &tally_results);
+        GovernanceEvents::submission_tallied(env, round, submission_id, tally_result.clone());
+        Ok(tally_result)
+    }
+
+    /// Preview the tally of a submission in the active round without storing it.
+    ///
+    /// Uses the same calculation and tally rules as `tally_submission`, but doesn't require a
+    /// particular round phase. Voting powers read by the simulation don't get their lifetime
+    /// extended.
+    ///
+    /// # Arguments
+    ///
+    /// * `powers_override`: voting powers to tally with instead of those calculated for the round
+    pub fn simulate_tally(
+        env: &Env,
+        submission_id: String,
+        powers_override: Option<Map<String, I256>>,
+    ) -> Result<TallyResult, VotingSystemError> {
+        let round = Self::get_current_round(env);
+        let voting_powers = match powers_override {
+            Some(voting_powers) => voting_powers,
+            None => read_voting_powers(env, round, false)?,
+        };
+        tally_submission_votes(env, round, &submission_id, &voting_powers)
+    }
+
+    /// Tally a page of submissions of the active round.
+    ///
+    /// Tallies up to `limit` submissions starting at `offset` in the order returned by
+    /// `get_submissions`, so rounds with many submissions can be tallied in multiple calls within
+    /// resource limits. Submissions without any votes score zero and don't pass.
+    ///
+    /// Returns the offset of the next page, which equals the number of submissions once the whole
+    /// round is tallied. Anyone can tally once voting powers are calculated, the results are
+    /// provisional like those of `tally_submission`.
+    pub fn tally_round(env: &Env, offset: u32, limit: u32) -> Result<u32, VotingSystemError> {
+        let round = Self::get_current_round(env);
+        require_phase(env, round, &[RoundPhase::PowersCalculated])?;
+
+        let submissions = read_submissions(env, round)?;
+        let mut voting_powers = read_voting_powers(env, round, true)?;
+        let rules = read_tally_rules(env, round);
+        let yes_votes = read_yes_votes(env, round, &rules)?;
+        let mut tally_results: Map<String, TallyResult> = match read_tally_results(env, round) {
+            Ok(tally_results) => tally_results,
+            Err(_) => Map::new(env),
+        };
+
+        let end = offset.saturating_add(limit).min(submissions.len());
+        for index in offset..end {
+            let submission = submissions.get_unchecked(index);
+            let submission_id = submission.id;
+            let category = submission.category.name(env);
+            let submission_votes = match read_tallied_votes(env, round, &submission_id) {
+                Ok(submission_votes) => submission_votes,
+                Err(VotingSystemError::VotesForSubmissionNotSet) => Map::new(env),
+                Err(err) => return Err(err),
             };
+            add_missing_voting_powers(env, round, &submission_votes, &mut voting_powers);
+            let tally_result = tally_votes(
+                env,
+                round,
+                submission_votes,
+                Some(category),
+                &voting_powers,
+                &rules,
+                &yes_votes,
+            )?;
+            tally_results.set(submission_id.clone(), tally_result.clone());
+            GovernanceEvents::submission_tallied(env, round, submission_id, tally_result);
         }
-        let tally_result: I256 = submission_voting_power_plus.sub(&submission_voting_power_minus);
-        let mut tally_results: Map<String, I256> =
-            match read_tally_results(env, Self::get_current_round(env)) {
-                Ok(tally_results) => tally_results,
-                Err(_) => Map::new(env),
-            };
-        tally_results.set(submission_id, tally_result.clone());
-        write_tally_results(env, Self::get_current_round(env), &tally_results);
-        Ok(tally_result)
+
+        write_tally_results(env, round, &tally_results);
+        Ok(end.max(offset))
+    }
+
+    /// Fix the tally results of the active round, moving it to the `Tallied` phase, after which
+    /// votes, voting powers and tally rules can't change.
+    ///
+    /// # Errors:
+    ///
+    /// Fails with `InvalidRoundPhase` if voting powers are not calculated for the active round and
+    /// with `TallyResultsNotSet` if any submission of the round is not tallied.
+    pub fn finalize_tally(env: Env) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        require_phase(&env, round, &[RoundPhase::PowersCalculated])?;
+
+        let tally_results = read_tally_results(&env, round).unwrap_or_else(|_| Map::new(&env));
+        for submission in read_submissions(&env, round)? {
+            if !tally_results.contains_key(submission.id) {
+                return Err(VotingSystemError::TallyResultsNotSet);
+            }
+        }
+        set_phase(&env, round, RoundPhase::Tallied);
+
+        Ok(())
+    }
+
+    /// Extend the TTL of all stored data of a round, keeping it available for audits.
+    ///
+    /// Round data is extended automatically whenever it's written, this is needed only to keep
+    /// older rounds around. Neuron results are extended for neurons of the current layers.
+    pub fn extend_round_ttl(env: Env, round: u32) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        extend_instance(&env);
+        extend_round(&env, round)
     }
 
     /// Get tally results for all submissions for a specific round.
     pub fn get_tally_results(
         env: &Env,
         round: u32,
-    ) -> Result<Map<String, I256>, VotingSystemError> {
+    ) -> Result<Map<String, TallyResult>, VotingSystemError> {
         read_tally_results(env, round)
     }
+
+    /// Get tallied submissions of a specific round grouped by category and ordered by score,
+    /// highest first.
+    pub fn get_ranked_results(
+        env: &Env,
+        round: u32,
+    ) -> Result<Map<String, Vec<(String, I256)>>, VotingSystemError> {
+        let tally_results = read_tally_results(env, round)?;
+        Ok(rank_results(
+            env,
+            &read_submissions(env, round)?,
+            &tally_results,
+        ))
+    }
+
+    /// Set the award budget of a submission category for the active round.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: name of a `SubmissionCategory`
+    pub fn set_category_budget(
+        env: Env,
+        category: String,
+        budget: CategoryBudget,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+        validate_category(&env, &category)?;
+
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+                RoundPhase::Tallied,
+            ],
+        )?;
+        validate_budget(&budget)?;
+
+        let mut budgets = read_budgets(&env, round).unwrap_or_else(|_| Map::new(&env));
+        budgets.set(category.clone(), budget.clone());
+        write_budgets(&env, round, &budgets);
+        GovernanceEvents::budget_set(&env, round, category, budget);
+
+        Ok(())
+    }
+
+    /// Get award budgets of submission categories for a specific round.
+    pub fn get_category_budgets(
+        env: &Env,
+        round: u32,
+    ) -> Result<Map<String, CategoryBudget>, VotingSystemError> {
+        read_budgets(env, round)
+    }
+
+    /// Get award amounts of submissions for a specific round.
+    ///
+    /// Budget of each category is split between its passed submissions according to the rules of
+    /// the category budget. Only awarded submissions are included.
+    ///
+    /// # Errors:
+    ///
+    /// Fails with `InvalidRoundPhase` until the tally of the round is finalized with
+    /// `finalize_tally`, so awards are never computed from provisional tally results.
+    pub fn get_allocations(env: &Env, round: u32) -> Result<Map<String, i128>, VotingSystemError> {
+        require_phase(env, round, &[RoundPhase::Tallied, RoundPhase::Finalized])?;
+
+        let budgets = read_budgets(env, round)?;
+        let tally_results = read_tally_results(env, round)?;
+        let ranked = rank_results(env, &read_submissions(env, round)?, &tally_results);
+
+        let mut allocations = Map::new(env);
+        for (category, budget) in budgets {
+            let Some(ranking) = ranked.get(category) else {
+                continue;
+            };
+            let passed = Vec::from_iter(
+                env,
+                ranking.iter().filter(|(submission_id, _score)| {
+                    tally_results
+                        .get(submission_id.clone())
+                        .is_some_and(|result| result.passed)
+                }),
+            );
+            for (submission_id, award) in allocate_budget(env, &budget, &passed)? {
+                if award > 0 {
+                    allocations.set(submission_id, award);
+                }
+            }
+        }
+
+        Ok(allocations)
+    }
 }
 
 #[contractimpl]
@@ -217,13 +1040,63 @@ impl Admin for VotingSystem {
     fn transfer_admin(env: Env, new_admin: Address) {
         require_admin(&env);
 
+        set_pending_admin(&env, &new_admin);
+        GovernanceEvents::admin_transfer_proposed(&env, new_admin);
+    }
+
+    fn accept_admin(env: Env) {
+        let Some(new_admin) = get_pending_admin(&env) else {
+            panic_with_error!(&env, VotingSystemError::NoPendingAdmin);
+        };
+        new_admin.require_auth();
+
         set_admin(&env, &new_admin);
+        remove_pending_admin(&env);
+        GovernanceEvents::admin_transferred(&env, new_admin);
+    }
+
+    fn cancel_admin_transfer(env: Env) {
+        require_admin(&env);
+
+        let Some(pending_admin) = get_pending_admin(&env) else {
+            panic_with_error!(&env, VotingSystemError::NoPendingAdmin);
+        };
+        remove_pending_admin(&env);
+        GovernanceEvents::admin_transfer_cancelled(&env, pending_admin);
+    }
+
+    fn get_pending_admin(env: Env) -> Option<Address> {
+        get_pending_admin(&env)
     }
 
     fn upgrade(env: Env, wasm_hash: BytesN<32>) {
         require_admin(&env);
 
-        env.deployer().update_current_contract_wasm(wasm_hash);
+        env.deployer()
+            .update_current_contract_wasm(wasm_hash.clone());
+        GovernanceEvents::upgraded(&env, wasm_hash);
+    }
+
+    fn grant_role(env: Env, role: Role, holder: Address) {
+        require_admin(&env);
+
+        set_role_holder(&env, role, &holder);
+        GovernanceEvents::role_granted(&env, role, holder);
+    }
+
+    fn revoke_role(env: Env, role: Role) {
+        require_admin(&env);
+
+        remove_role_holder(&env, role);
+        GovernanceEvents::role_revoked(&env, role);
+    }
+
+    fn get_role_holder(env: Env, role: Role) -> Address {
+        get_role_holder(&env, role)
+    }
+
+    fn has_role(env: Env, role: Role, address: Address) -> bool {
+        get_role_holder(&env, role) == address
     }
 }
 
@@ -234,18 +1107,23 @@ impl Governance for VotingSystem {
         raw_neurons: Vec<(String, I256)>,
         layer_aggregator: LayerAggregator,
     ) -> Result<(), VotingSystemError> {
-        require_admin(&env);
+        require_role(&env, Role::Configurator);
 
         let layer_id = next_layer_id(&env);
         let layer_id = String::from_str(&env, layer_id.to_string().as_str());
 
-        create_or_update_layer(env, layer_id, raw_neurons, layer_aggregator);
+        let layer = create_or_update_layer(&env, layer_id.clone(), raw_neurons, layer_aggregator)?;
+
+        let mut neural_governance = read_neural_governance(&env)?;
+        neural_governance.layers.push_back(layer_id.clone());
+        write_neural_governance(&env, neural_governance);
+        GovernanceEvents::layer_added(&env, layer_id, layer.neurons, layer.aggregator);
 
         Ok(())
     }
 
     fn remove_layer(env: Env, layer_id: String) -> Result<(), VotingSystemError> {
-        require_admin(&env);
+        require_role(&env, Role::Configurator);
 
         let mut neural_governance = read_neural_governance(&env).unwrap();
         let index = neural_governance
@@ -254,17 +1132,27 @@ impl Governance for VotingSystem {
             .position(|id| id == layer_id)
             .ok_or(VotingSystemError::LayerMissing)?;
         let layer = read_layer(&env, &layer_id)?;
+        if neural_governance
+            .layers
+            .iter()
+            .any(|other| read_layer_inputs(&env, &other).contains(&layer_id))
+        {
+            return Err(VotingSystemError::LayerInUse);
+        }
 
         for neuron_id in layer.neurons {
             remove_neuron(&env, &layer_id, &neuron_id);
         }
         remove_layer(&env, &layer_id);
+        remove_layer_inputs(&env, &layer_id);
 
         neural_governance
             .layers
             .remove(u32::try_from(index).unwrap());
+        neural_governance.layer_weights.remove(layer_id.clone());
 
         write_neural_governance(&env, neural_governance);
+        GovernanceEvents::layer_removed(&env, layer_id);
 
         Ok(())
     }
@@ -275,16 +1163,89 @@ impl Governance for VotingSystem {
         raw_neurons: Vec<(String, I256)>,
         layer_aggregator: LayerAggregator,
     ) -> Result<(), VotingSystemError> {
-        require_admin(&env);
+        require_role(&env, Role::Configurator);
+
+        let previous = read_layer(&env, &layer_id)?;
+        let mut previous_names = Vec::new(&env);
+        for neuron_id in previous.neurons.iter() {
+            let name =
+                read_neuron(&env, &layer_id, &neuron_id).map_or(neuron_id, |neuron| neuron.name);
+            previous_names.push_back(name);
+        }
 
-        let layer = read_layer(&env, &layer_id)?;
+        let layer = create_or_update_layer(&env, layer_id.clone(), raw_neurons, layer_aggregator)?;
+        for neuron_id in previous.neurons.iter() {
+            if !layer.neurons.contains(&neuron_id) {
+                remove_neuron(&env, &layer_id, &neuron_id);
+            }
+        }
+        migrate_legacy_neuron_results(
+            &env,
+            &layer_id,
+            &previous.neurons,
+            &previous_names,
+            &layer.neurons,
+        );
 
-        for neuron_id in layer.neurons {
-            remove_neuron(&env, &layer_id, &neuron_id);
+        // earlier versions pushed the layer ID again on every update
+        let mut neural_governance = read_neural_governance(&env)?;
+        let mut layers = Vec::new(&env);
+        for id in neural_governance.layers.iter() {
+            if !layers.contains(&id) {
+                layers.push_back(id);
+            }
+        }
+        if layers != neural_governance.layers {
+            neural_governance.layers = layers;
+            write_neural_governance(&env, neural_governance);
         }
+        GovernanceEvents::layer_updated(&env, layer_id, layer.neurons, layer.aggregator);
+
+        Ok(())
+    }
 
-        create_or_update_layer(env, layer_id, raw_neurons, layer_aggregator);
+    fn add_neuron(
+        env: Env,
+        layer_id: String,
+        name: String,
+        weight: I256,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::Configurator);
 
+        let mut layer = read_layer(&env, &layer_id)?;
+        if layer.neurons.contains(&name) {
+            return Err(VotingSystemError::NeuronExists);
+        }
+
+        write_neuron(
+            &env,
+            &layer_id,
+            &name,
+            &Neuron::create(name.clone(), weight.clone()),
+        );
+        layer.neurons.push_back(name.clone());
+        write_layer(&env, &layer_id, &layer);
+        GovernanceEvents::neuron_added(&env, layer_id, name, weight);
+        Ok(())
+    }
+
+    fn remove_neuron(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::Configurator);
+
+        let mut layer = read_layer(&env, &layer_id)?;
+        let index = layer
+            .neurons
+            .first_index_of(&neuron_id)
+            .ok_or(VotingSystemError::NeuronMissing)?;
+
+        remove_neuron(&env, &layer_id, &neuron_id);
+        layer.neurons.remove(index);
+        write_layer(&env, &layer_id, &layer);
+        GovernanceEvents::neuron_removed(&env, layer_id, neuron_id);
         Ok(())
     }
 
@@ -306,7 +1267,7 @@ impl Governance for VotingSystem {
         neuron_id: String,
         round: u32,
     ) -> Result<Map<String, I256>, VotingSystemError> {
-        read_neuron_result(env, &layer_id, &neuron_id, round)
+        load_neuron_result(env, &layer_id, &neuron_id, round)
     }
 
     fn get_neuron_result(
@@ -317,114 +1278,776 @@ impl Governance for VotingSystem {
         Self::get_neuron_result_round(env, layer_id, neuron_id, Self::get_current_round(env))
     }
 
-    fn set_neuron_result(env: Env, layer_id: String, neuron_id: String, result: Map<String, I256>) {
-        require_admin(&env);
+    fn set_neuron_result(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+        result: Map<String, I256>,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::DataUploader);
 
-        write_neuron_result(
+        let round = Self::get_current_round(&env);
+        require_phase(
             &env,
-            &layer_id,
-            &neuron_id,
-            Self::get_current_round(&env),
-            &result,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+        read_neuron(&env, &layer_id, &neuron_id)?;
+        require_eligible_voters(&env, round, &result.keys())?;
+
+        write_neuron_result(&env, &layer_id, &neuron_id, round, &result);
+        remove_neuron_result_root(&env, &layer_id, &neuron_id, round);
+        remove_neuron_result_upload(&env, &layer_id, &neuron_id, round);
+        reset_voting_powers(&env, round);
+        GovernanceEvents::neuron_result_set(&env, round, layer_id, neuron_id, result.len());
+        Ok(())
+    }
+
+    fn append_neuron_result_chunk(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+        result: Map<String, I256>,
+    ) -> Result<u32, VotingSystemError> {
+        require_role(&env, Role::DataUploader);
+
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+        read_neuron(&env, &layer_id, &neuron_id)?;
+        require_eligible_voters(&env, round, &result.keys())?;
+
+        let upload = append_neuron_result_chunk(&env, &layer_id, &neuron_id, round, &result)?;
+        remove_neuron_result_root(&env, &layer_id, &neuron_id, round);
+        reset_voting_powers(&env, round);
+        GovernanceEvents::neuron_result_chunk_appended(
+            &env,
+            round,
+            layer_id,
+            neuron_id,
+            upload.chunks - 1,
+            result.len(),
         );
+        Ok(upload.chunks)
+    }
+
+    fn seal_neuron_result(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::DataUploader);
+
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+        read_neuron(&env, &layer_id, &neuron_id)?;
+
+        let upload = seal_neuron_result(&env, &layer_id, &neuron_id, round)?;
+        reset_voting_powers(&env, round);
+        GovernanceEvents::neuron_result_sealed(&env, round, layer_id, neuron_id, upload.chunks);
+        Ok(())
+    }
+
+    fn reset_neuron_result_upload(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::DataUploader);
+
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+
+        let upload = read_neuron_result_upload(&env, &layer_id, &neuron_id, round)
+            .ok_or(VotingSystemError::NeuronResultNotSet)?;
+        remove_neuron_result_upload(&env, &layer_id, &neuron_id, round);
+        reset_voting_powers(&env, round);
+        GovernanceEvents::neuron_result_upload_reset(
+            &env,
+            round,
+            layer_id,
+            neuron_id,
+            upload.chunks,
+        );
+        Ok(())
+    }
+
+    fn set_neuron_result_root(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+        root: BytesN<32>,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::DataUploader);
+
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+                RoundPhase::PowersCalculated,
+            ],
+        )?;
+        read_neuron(&env, &layer_id, &neuron_id)?;
+
+        write_neuron_result_root(&env, &layer_id, &neuron_id, round, &root);
+        remove_neuron_result(&env, &layer_id, &neuron_id, round);
+        remove_neuron_result_upload(&env, &layer_id, &neuron_id, round);
+        reset_voting_powers(&env, round);
+        GovernanceEvents::neuron_result_root_set(&env, round, layer_id, neuron_id, root);
+        Ok(())
+    }
+
+    fn get_neuron_result_root(
+        env: &Env,
+        layer_id: String,
+        neuron_id: String,
+        round: u32,
+    ) -> Result<BytesN<32>, VotingSystemError> {
+        read_neuron_result_root(env, &layer_id, &neuron_id, round)
+    }
+
+    fn claim_voting_power(
+        env: Env,
+        voter: Address,
+        layer_id: String,
+        neuron_id: String,
+        value: I256,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), VotingSystemError> {
+        voter.require_auth();
+
+        let round = Self::get_current_round(&env);
+        require_phase(
+            &env,
+            round,
+            &[
+                RoundPhase::Setup,
+                RoundPhase::Voting,
+                RoundPhase::Reveal,
+                RoundPhase::VotingClosed,
+            ],
+        )?;
+
+        let root = read_neuron_result_root(&env, &layer_id, &neuron_id, round)?;
+        let voter_id = voter.to_string();
+        require_eligible_voters(&env, round, &Vec::from_array(&env, [voter_id.clone()]))?;
+        if !verify_proof(&env, &root, leaf_hash(&env, &voter_id, &value), &proof) {
+            return Err(VotingSystemError::InvalidProof);
+        }
+
+        write_neuron_claim(&env, &layer_id, &neuron_id, round, &root, &voter, &value);
+        GovernanceEvents::voting_power_claimed(&env, round, voter, layer_id, neuron_id, value);
+        Ok(())
     }
 
     /// Get a result of a whole layer
     ///
-    /// Gets a result of each neuron and aggregates them using a configured aggregator function
+    /// Gets a result of each neuron and of each input layer and aggregates them using a configured
+    /// aggregator function
     fn get_layer_result(
         env: Env,
         layer_id: String,
     ) -> Result<Map<String, I256>, VotingSystemError> {
-        let layer = read_layer(&env, &layer_id)?;
-        let mut result: Map<String, Vec<I256>> = Map::new(&env);
+        let round = Self::get_current_round(&env);
+        let config = read_neural_governance_config(&env)?;
+        let layer_results =
+            calculate_layer_results(&env, &config, round, Some(&layer_id), None, true)?;
+        layer_results
+            .get(layer_id)
+            .ok_or(VotingSystemError::LayerMissing)
+    }
 
-        for neuron_id in layer.neurons {
-            let neuron_result = Self::get_neuron_result(&env, layer_id.clone(), neuron_id.clone())?;
-            let neuron = read_neuron(&env, &layer_id, &neuron_id)?;
-            let neuron_result = weigh_neuron_result(&env, &neuron.weight, neuron_result);
-            for (user, new) in neuron_result {
-                let mut previous = result.get(user.clone()).unwrap_or_else(|| Vec::new(&env));
-                previous.push_back(new);
-                result.set(user, previous);
+    fn set_layer_inputs(
+        env: Env,
+        layer_id: String,
+        inputs: Vec<String>,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::Configurator);
+
+        let neural_governance = read_neural_governance(&env)?;
+        read_layer(&env, &layer_id)?;
+        for (index, input) in inputs.iter().enumerate() {
+            if !neural_governance.layers.contains(&input) {
+                return Err(VotingSystemError::LayerMissing);
+            }
+            if inputs.iter().skip(index + 1).any(|other| other == input) {
+                return Err(VotingSystemError::UnexpectedValue);
             }
         }
 
-        Ok(aggregate_result(
-            &env,
-            result,
-            layer.aggregator,
-            I256::from_i128(&env, DECIMALS),
-        ))
+        let mut network = read_layer_network(&env, &neural_governance);
+        network.set(layer_id.clone(), inputs.clone());
+        evaluation_order(&env, &neural_governance.layers, &network)?;
+
+        write_layer_inputs(&env, &layer_id, &inputs);
+        GovernanceEvents::layer_inputs_set(&env, layer_id, inputs);
+        Ok(())
     }
 
-    fn calculate_voting_powers(env: Env) -> Result<(), VotingSystemError> {
-        require_admin(&env);
+    fn get_layer_inputs(env: Env, layer_id: String) -> Result<Vec<String>, VotingSystemError> {
+        read_layer(&env, &layer_id)?;
+        Ok(read_layer_inputs(&env, &layer_id))
+    }
 
-        let neural_governance = read_neural_governance(&env).unwrap();
-        let mut result: Map<String, I256> = Map::new(&env);
-        for layer_id in neural_governance.layers {
-            let layer_result = VotingSystem::get_layer_result(env.clone(), layer_id)?;
-            for (key, value) in layer_result {
-                result.set(
-                    key.clone(),
-                    value.add(&result.get(key).unwrap_or_else(|| I256::from_i32(&env, 0))),
-                );
-            }
+    fn set_layer_weight(env: Env, layer_id: String, weight: I256) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::Configurator);
+
+        let mut neural_governance = read_neural_governance(&env)?;
+        if !neural_governance.layers.contains(&layer_id) {
+            return Err(VotingSystemError::LayerMissing);
+        }
+        if weight < I256::from_i32(&env, 0) {
+            return Err(VotingSystemError::UnexpectedValue);
         }
 
-        write_voting_powers(&env, Self::get_current_round(&env), &result);
+        neural_governance
+            .layer_weights
+            .set(layer_id.clone(), weight.clone());
+        write_neural_governance(&env, neural_governance);
+        GovernanceEvents::layer_weight_set(&env, layer_id, weight);
+        Ok(())
+    }
+
+    fn set_layer_combiner(env: Env, combiner: LayerCombiner) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::Configurator);
+
+        let mut neural_governance = read_neural_governance(&env)?;
+        neural_governance.combiner = combiner.clone();
+        write_neural_governance(&env, neural_governance);
+        GovernanceEvents::layer_combiner_set(&env, combiner);
+        Ok(())
+    }
+
+    fn calculate_voting_powers(env: Env) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::RoundOperator);
+
+        let round = Self::get_current_round(&env);
+        match read_voting_mode(&env, round) {
+            VotingMode::Open => require_phase(
+                &env,
+                round,
+                &[
+                    RoundPhase::Setup,
+                    RoundPhase::Voting,
+                    RoundPhase::VotingClosed,
+                    RoundPhase::PowersCalculated,
+                ],
+            )?,
+            // committed votes have to be revealed before the voting powers are fixed
+            VotingMode::CommitReveal => require_phase(
+                &env,
+                round,
+                &[
+                    RoundPhase::Reveal,
+                    RoundPhase::VotingClosed,
+                    RoundPhase::PowersCalculated,
+                ],
+            )?,
+        };
+
+        let config = read_neural_governance_config(&env)?;
+        let result = calculate_round_voting_powers(&env, &config, round, true)?;
+
+        write_voting_powers(&env, round, &result);
+        write_neural_governance_round(&env, round, &config);
+        remove_tally_results(&env, round);
+        GovernanceEvents::voting_powers_calculated(&env, round, result.len());
+        set_phase(&env, round, RoundPhase::PowersCalculated);
+        Ok(())
+    }
+
+    fn simulate_voting_powers(
+        env: Env,
+        config_override: Option<NeuralGovernanceRound>,
+    ) -> Result<Map<String, I256>, VotingSystemError> {
+        let config = match config_override {
+            Some(config) => config,
+            None => read_neural_governance_config(&env)?,
+        };
+        calculate_round_voting_powers(&env, &config, Self::get_current_round(&env), false)
+    }
+
+    fn set_voting_power_policy(
+        env: Env,
+        policy: VotingPowerPolicy,
+    ) -> Result<(), VotingSystemError> {
+        require_role(&env, Role::Configurator);
+
+        validate_policy(&env, &policy, &I256::from_i128(&env, DECIMALS))?;
+        write_voting_power_policy(&env, &policy);
+        GovernanceEvents::voting_power_policy_set(&env, policy);
         Ok(())
     }
 
+    fn get_voting_power_policy(env: Env) -> VotingPowerPolicy {
+        read_voting_power_policy(&env)
+    }
+
+    fn get_voting_power_breakdown(
+        env: Env,
+        round: u32,
+        voter_id: String,
+    ) -> Result<VotingPowerBreakdown, VotingSystemError> {
+        let voting_power = read_voting_powers(&env, round, true)?
+            .get(voter_id.clone())
+            .ok_or(VotingSystemError::NGQResultForVoterMissing)?;
+
+        // rounds calculated before configurations were stored use the current one
+        let config = read_neural_governance_round(&env, round)
+            .or_else(|_| read_neural_governance_config(&env))?;
+        let mut neurons = Vec::new(&env);
+        let layer_results = calculate_layer_results(
+            &env,
+            &config,
+            round,
+            None,
+            Some((&voter_id, &mut neurons)),
+            true,
+        )?;
+        let mut layers = Vec::new(&env);
+        for (layer_id, layer_result) in layer_results.iter() {
+            if let Some(value) = layer_result.get(voter_id.clone()) {
+                layers.push_back(LayerBreakdown { layer_id, value });
+            }
+        }
+        let combined_power = combine_layer_results(&env, &config, &layer_results)?
+            .get(voter_id)
+            .unwrap_or_else(|| I256::from_i32(&env, 0));
+
+        Ok(VotingPowerBreakdown {
+            neurons,
+            layers,
+            combined_power,
+            voting_power,
+        })
+    }
+
     fn get_voting_powers(env: Env) -> Result<Map<String, I256>, VotingSystemError> {
-        read_voting_powers(&env, Self::get_current_round(&env))
+        read_voting_powers(&env, Self::get_current_round(&env), true)
     }
 
     /// Get a current neural governance setup
     fn get_neural_governance(env: &Env) -> Result<NGQ, VotingSystemError> {
         read_neural_governance(env)
     }
+
+    fn get_neural_governance_round(
+        env: &Env,
+        round: u32,
+    ) -> Result<NeuralGovernanceRound, VotingSystemError> {
+        read_neural_governance_round(env, round)
+    }
 }
 
+/// Write neurons of a layer and the layer itself. Neurons are identified by their names.
 fn create_or_update_layer(
-    env: Env,
+    env: &Env,
     layer_id: String,
     raw_neurons: Vec<(String, I256)>,
     layer_aggregator: LayerAggregator,
+) -> ContractResult<Layer> {
+    let mut neurons = Vec::new(env);
+
+    for (name, weight) in raw_neurons {
+        if neurons.contains(&name) {
+            return Err(VotingSystemError::NeuronExists);
+        }
+
+        let neuron_details = Neuron::create(name.clone(), weight);
+        write_neuron(env, &layer_id, &name, &neuron_details);
+
+        neurons.push_back(name);
+    }
+
+    let layer = Layer::create(neurons, layer_aggregator);
+    write_layer(env, &layer_id, &layer);
+
+    Ok(layer)
+}
+
+/// Move neuron results of the active round from the positions of the neurons in a layer to their
+/// names.
+///
+/// Layers created before neurons were identified by names use positions (`"0"`, `"1"`, ...) as
+/// neuron IDs until they are updated, and their results are uploaded under these IDs. All results
+/// are taken out before any is stored again, so a position ID that is also the name of another
+/// neuron doesn't mix up their results. Results already stored under a neuron name are kept.
+fn migrate_legacy_neuron_results(
+    env: &Env,
+    layer_id: &String,
+    previous_ids: &Vec<String>,
+    previous_names: &Vec<String>,
+    neurons: &Vec<String>,
 ) {
-    let mut neural_governance = read_neural_governance(&env).unwrap();
+    let round = VotingSystem::get_current_round(env);
+    let mut taken = alloc::vec::Vec::new();
+    for (neuron_id, name) in previous_ids.iter().zip(previous_names.iter()) {
+        if neuron_id != name && neurons.contains(&name) {
+            taken.push((name, take_neuron_result(env, layer_id, &neuron_id, round)));
+        }
+    }
+    for (name, stored) in taken {
+        put_neuron_result(env, layer_id, &name, round, stored);
+    }
+}
 
-    let mut neurons = Vec::new(&env);
+/// Inputs of every layer of the neural governance.
+fn read_layer_network(env: &Env, neural_governance: &NGQ) -> Map<String, Vec<String>> {
+    let mut network = Map::new(env);
+    for layer_id in neural_governance.layers.iter() {
+        let inputs = read_layer_inputs(env, &layer_id);
+        if !inputs.is_empty() {
+            network.set(layer_id, inputs);
+        }
+    }
+    network
+}
 
-    for (neuron_id_raw, (name, weight)) in raw_neurons.into_iter().enumerate() {
-        let neuron_id = String::from_str(&env, neuron_id_raw.to_string().as_str());
+/// Read the current neural governance configuration, as it would be used for the active round.
+fn read_neural_governance_config(env: &Env) -> ContractResult<NeuralGovernanceRound> {
+    let neural_governance = read_neural_governance(env)?;
+
+    let mut layers = Map::new(env);
+    let mut neurons = Map::new(env);
+    for layer_id in neural_governance.layers.iter() {
+        let layer = read_layer(env, &layer_id)?;
+        let mut layer_neurons = Map::new(env);
+        for neuron_id in layer.neurons.iter() {
+            layer_neurons.set(neuron_id.clone(), read_neuron(env, &layer_id, &neuron_id)?);
+        }
+        neurons.set(layer_id.clone(), layer_neurons);
+        layers.set(layer_id, layer);
+    }
 
-        let neuron_details = Neuron::create(name, weight);
-        write_neuron(&env, &layer_id, &neuron_id, &neuron_details);
+    Ok(NeuralGovernanceRound {
+        layer_inputs: read_layer_network(env, &neural_governance),
+        neural_governance,
+        layers,
+        neurons,
+        policy: read_voting_power_policy(env),
+    })
+}
 
-        neurons.push_back(neuron_id);
+/// Compute results of layers for a round in their evaluation order.
+///
+/// Stops after computing `last_layer_id`, if given. With `breakdown`, only values of a single voter
+/// are computed and values of every neuron the voter has a result in are collected. Neuron results
+/// are extended only with `extend`.
+fn calculate_layer_results(
+    env: &Env,
+    config: &NeuralGovernanceRound,
+    round: u32,
+    last_layer_id: Option<&String>,
+    mut breakdown: Option<(&String, &mut Vec<NeuronBreakdown>)>,
+    extend: bool,
+) -> ContractResult<Map<String, Map<String, I256>>> {
+    let mut layer_results = Map::new(env);
+    for layer_id in evaluation_order(env, &config.neural_governance.layers, &config.layer_inputs)? {
+        let layer_result = calculate_layer_result(
+            env,
+            config,
+            round,
+            &layer_id,
+            &layer_results,
+            breakdown
+                .as_mut()
+                .map(|(voter_id, neurons)| (*voter_id, &mut **neurons)),
+            extend,
+        )?;
+        layer_results.set(layer_id.clone(), layer_result);
+        if last_layer_id == Some(&layer_id) {
+            break;
+        }
     }
+    Ok(layer_results)
+}
 
-    let layer = Layer::create(neurons, layer_aggregator);
-    write_layer(&env, &layer_id, &layer);
+/// Aggregate weighted neuron results of the layer together with results of its input layers.
+fn calculate_layer_result(
+    env: &Env,
+    config: &NeuralGovernanceRound,
+    round: u32,
+    layer_id: &String,
+    layer_results: &Map<String, Map<String, I256>>,
+    mut breakdown: Option<(&String, &mut Vec<NeuronBreakdown>)>,
+    extend: bool,
+) -> ContractResult<Map<String, I256>> {
+    let layer = config.layer(layer_id)?;
+    let mut result: Map<String, Vec<I256>> = Map::new(env);
+    let mut add_values = |values: Map<String, I256>| {
+        for (user, new) in values {
+            let mut previous = result.get(user.clone()).unwrap_or_else(|| Vec::new(env));
+            previous.push_back(new);
+            result.set(user, previous);
+        }
+    };
+
+    for neuron_id in layer.neurons.iter() {
+        let neuron = config.neuron(layer_id, &neuron_id)?;
+        let Some((voter_id, neurons)) = breakdown.as_mut() else {
+            // chunks are weighed one at a time, later chunks override values of earlier ones
+            let mut weighted_result = Map::new(env);
+            for_each_neuron_result_part(env, layer_id, &neuron_id, round, extend, |part| {
+                weigh_neuron_result(env, &neuron.weight, part, &mut weighted_result);
+            })?;
+            add_values(weighted_result);
+            continue;
+        };
+
+        let mut raw_value = None;
+        for_each_neuron_result_part(env, layer_id, &neuron_id, round, extend, |part| {
+            if let Some(value) = part.get((*voter_id).clone()) {
+                raw_value = Some(value);
+            }
+        })?;
+        let Some(raw_value) = raw_value else {
+            continue;
+        };
+        let mut weighted_result = Map::new(env);
+        weigh_neuron_result(
+            env,
+            &neuron.weight,
+            Map::from_array(env, [((*voter_id).clone(), raw_value.clone())]),
+            &mut weighted_result,
+        );
+        neurons.push_back(NeuronBreakdown {
+            layer_id: layer_id.clone(),
+            neuron_id,
+            raw_value,
+            weighted_value: weighted_result.get((*voter_id).clone()).unwrap(),
+        });
+        add_values(weighted_result);
+    }
+    for input in config.inputs(env, layer_id) {
+        add_values(
+            layer_results
+                .get(input)
+                .ok_or(VotingSystemError::LayerMissing)?,
+        );
+    }
 
-    neural_governance.layers.push_back(layer_id);
-    write_neural_governance(&env, neural_governance);
+    Ok(aggregate_result(
+        env,
+        result,
+        layer.aggregator,
+        I256::from_i128(env, DECIMALS),
+    ))
 }
 
-fn weigh_neuron_result(env: &Env, weight: &I256, result: Map<String, I256>) -> Map<String, I256> {
-    let mut scaled = Map::new(env);
+/// Combine weighted results of the layers that are not inputs of other layers.
+fn combine_layer_results(
+    env: &Env,
+    config: &NeuralGovernanceRound,
+    layer_results: &Map<String, Map<String, I256>>,
+) -> ContractResult<Map<String, I256>> {
+    let neural_governance = &config.neural_governance;
+    let decimals = I256::from_i128(env, DECIMALS);
+
+    let mut weighted_results = Vec::new(env);
+    for layer_id in output_layers(env, &neural_governance.layers, &config.layer_inputs) {
+        let layer_result = layer_results
+            .get(layer_id.clone())
+            .ok_or(VotingSystemError::LayerMissing)?;
+        weighted_results.push_back((
+            neural_governance.layer_weight(&layer_id, &decimals),
+            layer_result,
+        ));
+    }
+    Ok(combine_results(
+        env,
+        weighted_results,
+        neural_governance.combiner.clone(),
+        decimals,
+    ))
+}
+
+/// Calculate voting powers of the voters of a round with the given neural governance
+/// configuration, including its voting power policy. Neuron results are extended only with
+/// `extend`.
+fn calculate_round_voting_powers(
+    env: &Env,
+    config: &NeuralGovernanceRound,
+    round: u32,
+    extend: bool,
+) -> ContractResult<Map<String, I256>> {
+    let layer_results = calculate_layer_results(env, config, round, None, None, extend)?;
+    let mut result = combine_layer_results(env, config, &layer_results)?;
+    retain_eligible_voters(env, round, &mut result);
+    let decimals = I256::from_i128(env, DECIMALS);
+    Ok(apply_policy(env, result, &config.policy, &decimals))
+}
+
+/// Tally votes of a submission of a round with the given voting powers and the tally rules of the
+/// round.
+fn tally_submission_votes(
+    env: &Env,
+    round: u32,
+    submission_id: &String,
+    voting_powers: &Map<String, I256>,
+) -> ContractResult<TallyResult> {
+    let submission_votes = read_tallied_votes(env, round, submission_id)?;
+    let mut voting_powers = voting_powers.clone();
+    add_missing_voting_powers(env, round, &submission_votes, &mut voting_powers);
+    let category = read_submissions(env, round)?
+        .iter()
+        .find(|submission| submission.id == *submission_id)
+        .map(|submission| submission.category.name(env));
+    let rules = read_tally_rules(env, round);
+    let yes_votes = read_yes_votes(env, round, &rules)?;
+    tally_votes(
+        env,
+        round,
+        submission_votes,
+        category,
+        &voting_powers,
+        &rules,
+        &yes_votes,
+    )
+}
+
+/// Votes of a submission of a round counted in the tally, without votes of voters who aren't
+/// eligible in the round.
+fn read_tallied_votes(
+    env: &Env,
+    round: u32,
+    submission_id: &String,
+) -> ContractResult<Map<String, Vote>> {
+    let mut votes =
+        VotingSystem::get_votes_for_submission_round(env, submission_id.clone(), round)?;
+    retain_eligible_voters(env, round, &mut votes);
+    Ok(votes)
+}
 
+fn read_submission_category(
+    env: &Env,
+    round: u32,
+    submission_id: &String,
+) -> ContractResult<SubmissionCategory> {
+    read_submissions(env, round)?
+        .iter()
+        .find(|submission| submission.id == *submission_id)
+        .map(|submission| submission.category)
+        .ok_or(VotingSystemError::SubmissionDoesNotExist)
+}
+
+/// Count the submissions of the round each voter voted `Yes` on, with `Delegate` votes resolved.
+///
+/// The counts are needed only in the `Budgeted` tally mode, the map is empty in other modes.
+fn read_yes_votes(env: &Env, round: u32, rules: &TallyRules) -> ContractResult<Map<String, u32>> {
+    let mut yes_votes = Map::new(env);
+    if rules.mode != TallyMode::Budgeted {
+        return Ok(yes_votes);
+    }
+
+    for submission in read_submissions(env, round)? {
+        let submission_votes = match read_tallied_votes(env, round, &submission.id) {
+            Ok(submission_votes) => submission_votes,
+            Err(VotingSystemError::VotesForSubmissionNotSet) => continue,
+            Err(err) => return Err(err),
+        };
+        let submission_votes = resolve_votes(
+            env,
+            round,
+            submission_votes,
+            Some(submission.category.name(env)),
+        )?;
+        count_yes_votes(&mut yes_votes, &submission_votes);
+    }
+
+    Ok(yes_votes)
+}
+
+fn require_voting_mode(env: &Env, round: u32, mode: VotingMode) -> ContractResult<()> {
+    if read_voting_mode(env, round) == mode {
+        Ok(())
+    } else {
+        Err(VotingSystemError::InvalidVotingMode)
+    }
+}
+
+/// Record a vote of a voter, validating the submission, eligibility of the voter and delegatees of
+/// `Delegate` votes.
+fn record_vote(
+    env: &Env,
+    round: u32,
+    voter: &Address,
+    submission_id: &String,
+    vote: Vote,
+) -> ContractResult<()> {
+    let category = read_submission_category(env, round, submission_id)?;
+    require_eligible_voters(env, round, &Vec::from_array(env, [voter.to_string()]))?;
+
+    if vote == Vote::Delegate {
+        read_delegatees(env, &voter.to_string(), &category.name(env), round)?;
+    }
+
+    if read_voter_vote(env, submission_id, round, voter).is_none() {
+        add_submission_voter(env, submission_id, round, voter);
+    }
+    write_voter_vote(env, submission_id, round, voter, &vote);
+    Ok(())
+}
+
+fn validate_category(env: &Env, category: &String) -> ContractResult<()> {
+    SubmissionCategory::from_name(env, category)
+        .map(|_| ())
+        .ok_or(VotingSystemError::InvalidSubmissionCategory)
+}
+
+/// Add the neuron result multiplied by the neuron weight to `weighted`.
+fn weigh_neuron_result(
+    env: &Env,
+    weight: &I256,
+    result: Map<String, I256>,
+    weighted: &mut Map<String, I256>,
+) {
     for (key, value) in result {
-        scaled.set(
+        weighted.set(
             key,
             // value.fixed_mul_floor(env, weight, &I256::from_i128(env, DECIMALS)),
             fixed_mul_floor(env, &value, weight, &I256::from_i128(env, DECIMALS)),
         );
     }
-
-    scaled
 }
 
 fn next_layer_id(env: &Env) -> u32 {
@@ -0,0 +1,115 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Bytes, BytesN, Env, String, Vec, I256};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Hash of a single neuron result entry: `sha256(0x00 || xdr((voter_id, value)))`.
+pub(crate) fn leaf_hash(env: &Env, voter_id: &String, value: &I256) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &[LEAF_PREFIX]);
+    data.append(&(voter_id.clone(), value.clone()).to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+/// Hash of two sibling nodes: `sha256(0x01 || min(a, b) || max(a, b))`.
+///
+/// Siblings are sorted, so proofs don't need to carry the position of each node.
+pub(crate) fn node_hash(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a < b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &[NODE_PREFIX]);
+    data.append(&left.clone().into());
+    data.append(&right.clone().into());
+    env.crypto().sha256(&data).into()
+}
+
+/// Check that `leaf` is included in the tree with the given `root`.
+pub(crate) fn verify_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| node_hash(env, &node, &sibling));
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::vec;
+
+    fn leaves(env: &Env) -> [BytesN<32>; 3] {
+        [
+            leaf_hash(
+                env,
+                &String::from_str(env, "user1"),
+                &I256::from_i32(env, 10),
+            ),
+            leaf_hash(
+                env,
+                &String::from_str(env, "user2"),
+                &I256::from_i32(env, 20),
+            ),
+            leaf_hash(
+                env,
+                &String::from_str(env, "user3"),
+                &I256::from_i32(env, 30),
+            ),
+        ]
+    }
+
+    #[test]
+    fn verifying_proof() {
+        let env = Env::default();
+
+        let [leaf1, leaf2, leaf3] = leaves(&env);
+        let node12 = node_hash(&env, &leaf1, &leaf2);
+        let root = node_hash(&env, &node12, &leaf3);
+
+        assert!(verify_proof(
+            &env,
+            &root,
+            leaf1.clone(),
+            &vec![&env, leaf2.clone(), leaf3.clone()]
+        ));
+        assert!(verify_proof(
+            &env,
+            &root,
+            leaf2,
+            &vec![&env, leaf1.clone(), leaf3.clone()]
+        ));
+        assert!(verify_proof(&env, &root, leaf3, &vec![&env, node12]));
+    }
+
+    #[test]
+    fn rejecting_invalid_proof() {
+        let env = Env::default();
+
+        let [leaf1, leaf2, leaf3] = leaves(&env);
+        let root = node_hash(&env, &node_hash(&env, &leaf1, &leaf2), &leaf3);
+
+        // value of user1 doesn't match the committed one
+        let forged = leaf_hash(
+            &env,
+            &String::from_str(&env, "user1"),
+            &I256::from_i32(&env, 11),
+        );
+        assert!(!verify_proof(
+            &env,
+            &root,
+            forged,
+            &vec![&env, leaf2.clone(), leaf3.clone()]
+        ));
+        assert!(!verify_proof(&env, &root, leaf1, &vec![&env, leaf3]));
+    }
+
+    #[test]
+    fn single_leaf_tree() {
+        let env = Env::default();
+
+        let [leaf1, _leaf2, _leaf3] = leaves(&env);
+        assert!(verify_proof(&env, &leaf1, leaf1.clone(), &Vec::new(&env)));
+    }
+}
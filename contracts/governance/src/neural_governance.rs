@@ -1,9 +1,12 @@
 #![allow(non_upper_case_globals)]
-use crate::fixed_mul_floor::fixed_mul_floor;
+use crate::fixed_mul_floor::{fixed_mul_floor, mul_div_floor};
 
 // use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::{contracttype, Env, Map, String, Vec, I256};
 
+use crate::types::{VotingPowerPolicy, VotingSystemError};
+use crate::ContractResult;
+
 pub mod traits;
 
 #[contracttype]
@@ -11,6 +14,26 @@ pub mod traits;
 pub enum LayerAggregator {
     Sum,
     Product,
+    /// The strongest of the neuron outputs
+    Max,
+    /// The weakest of the neuron outputs
+    Min,
+    /// Arithmetic mean of the neuron outputs, rounded down
+    Mean,
+    /// Middle value of the neuron outputs, mean of the two middle values for an even count
+    Median,
+}
+
+/// Function combining outputs of the final layers into voting powers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LayerCombiner {
+    /// Sum of the weighted layer outputs
+    Sum,
+    /// Product of the weighted layer outputs
+    Product,
+    /// Sum of the weighted layer outputs divided by the sum of weights, rounded down
+    WeightedMean,
 }
 
 #[contracttype]
@@ -31,7 +54,7 @@ impl Neuron {
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Layer {
-    /// Vec of `neuron_id`s
+    /// Vec of `neuron_id`s, which are the neuron names
     pub neurons: Vec<String>,
     pub aggregator: LayerAggregator,
 }
@@ -52,14 +75,26 @@ impl Layer {
 pub struct NGQ {
     /// Vec of `layer_id`s
     pub layers: Vec<String>,
+    /// Weights of layer outputs, layers without a weight have a weight of 1
+    pub layer_weights: Map<String, I256>,
+    pub combiner: LayerCombiner,
 }
 
 impl NGQ {
     pub fn new(env: &Env) -> Self {
         Self {
             layers: Vec::new(env),
+            layer_weights: Map::new(env),
+            combiner: LayerCombiner::Sum,
         }
     }
+
+    /// Weight of the layer output as a fixed-point number with `decimals`.
+    pub fn layer_weight(&self, layer_id: &String, decimals: &I256) -> I256 {
+        self.layer_weights
+            .get(layer_id.clone())
+            .unwrap_or_else(|| decimals.clone())
+    }
 }
 
 pub(crate) fn aggregate_result(
@@ -76,6 +111,10 @@ pub(crate) fn aggregate_result(
                 .iter()
                 // .reduce(|acc, e| acc.fixed_mul_floor(env, &e, &decimals)),
                 .reduce(|acc, e| fixed_mul_floor(env, &acc, &e, &decimals)),
+            LayerAggregator::Max => res.iter().max(),
+            LayerAggregator::Min => res.iter().min(),
+            LayerAggregator::Mean => mean(env, &res),
+            LayerAggregator::Median => median(env, &res),
         }
         .unwrap_or_else(|| I256::from_i128(env, 0));
         aggregated_result.set(user, res);
@@ -83,9 +122,171 @@ pub(crate) fn aggregate_result(
     aggregated_result
 }
 
+/// Neural governance configuration used to calculate voting powers of a round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NeuralGovernanceRound {
+    pub neural_governance: NGQ,
+    /// Layers by `layer_id`
+    pub layers: Map<String, Layer>,
+    /// Neurons by `layer_id` and `neuron_id`
+    pub neurons: Map<String, Map<String, Neuron>>,
+    /// Inputs of layers consuming outputs of other layers, by `layer_id`
+    pub layer_inputs: Map<String, Vec<String>>,
+    pub policy: VotingPowerPolicy,
+}
+
+impl NeuralGovernanceRound {
+    pub fn layer(&self, layer_id: &String) -> ContractResult<Layer> {
+        self.layers
+            .get(layer_id.clone())
+            .ok_or(VotingSystemError::LayerMissing)
+    }
+
+    pub fn neuron(&self, layer_id: &String, neuron_id: &String) -> ContractResult<Neuron> {
+        self.neurons
+            .get(layer_id.clone())
+            .and_then(|neurons| neurons.get(neuron_id.clone()))
+            .ok_or(VotingSystemError::NeuronMissing)
+    }
+
+    pub fn inputs(&self, env: &Env, layer_id: &String) -> Vec<String> {
+        self.layer_inputs
+            .get(layer_id.clone())
+            .unwrap_or_else(|| Vec::new(env))
+    }
+}
+
+/// Combine weighted results of layers into a single result.
+///
+/// `layer_results` are tuples of layer weights and layer results. Voters missing from a layer
+/// result are combined from the layers they are present in.
+pub(crate) fn combine_results(
+    env: &Env,
+    layer_results: Vec<(I256, Map<String, I256>)>,
+    combiner: LayerCombiner,
+    decimals: I256,
+) -> Map<String, I256> {
+    let zero = I256::from_i32(env, 0);
+    let mut weighted: Map<String, Vec<(I256, I256)>> = Map::new(env);
+    for (weight, result) in layer_results {
+        for (user, value) in result {
+            let mut values = weighted.get(user.clone()).unwrap_or_else(|| Vec::new(env));
+            values.push_back((
+                weight.clone(),
+                fixed_mul_floor(env, &value, &weight, &decimals),
+            ));
+            weighted.set(user, values);
+        }
+    }
+
+    let mut combined = Map::new(env);
+    for (user, values) in weighted {
+        let weighted_sum = values
+            .iter()
+            .fold(zero.clone(), |acc, (_weight, value)| acc.add(&value));
+        let value = match combiner {
+            LayerCombiner::Sum => weighted_sum,
+            LayerCombiner::Product => values
+                .iter()
+                .map(|(_weight, value)| value)
+                .reduce(|acc, e| fixed_mul_floor(env, &acc, &e, &decimals))
+                .unwrap_or_else(|| zero.clone()),
+            LayerCombiner::WeightedMean => {
+                let weights_sum = values
+                    .iter()
+                    .fold(zero.clone(), |acc, (weight, _value)| acc.add(&weight));
+                if weights_sum == zero {
+                    zero.clone()
+                } else {
+                    mul_div_floor(env, &weighted_sum, &decimals, &weights_sum)
+                }
+            }
+        };
+        combined.set(user, value);
+    }
+    combined
+}
+
+/// Order layers so that every layer comes after the layers it takes as inputs.
+///
+/// Layers keep their relative order from `layers` where possible. Fails with `CyclicLayers` if
+/// the inputs form a cycle.
+pub(crate) fn evaluation_order(
+    env: &Env,
+    layers: &Vec<String>,
+    inputs: &Map<String, Vec<String>>,
+) -> ContractResult<Vec<String>> {
+    let mut ordered: Vec<String> = Vec::new(env);
+
+    while ordered.len() < layers.len() {
+        let placed = ordered.len();
+        for layer_id in layers.iter() {
+            if ordered.contains(&layer_id) {
+                continue;
+            }
+            let layer_inputs = inputs
+                .get(layer_id.clone())
+                .unwrap_or_else(|| Vec::new(env));
+            if layer_inputs.iter().all(|input| ordered.contains(&input)) {
+                ordered.push_back(layer_id);
+            }
+        }
+        if ordered.len() == placed {
+            return Err(VotingSystemError::CyclicLayers);
+        }
+    }
+
+    Ok(ordered)
+}
+
+/// Layers whose outputs are not consumed by any other layer. Their results make up the final
+/// voting powers.
+pub(crate) fn output_layers(
+    env: &Env,
+    layers: &Vec<String>,
+    inputs: &Map<String, Vec<String>>,
+) -> Vec<String> {
+    let mut outputs = Vec::new(env);
+    for layer_id in layers.iter() {
+        if !inputs
+            .values()
+            .iter()
+            .any(|layer_inputs| layer_inputs.contains(&layer_id))
+        {
+            outputs.push_back(layer_id);
+        }
+    }
+    outputs
+}
+
+fn mean(env: &Env, values: &Vec<I256>) -> Option<I256> {
+    let sum = values.iter().reduce(|acc, e| acc.add(&e))?;
+    let count = I256::from_i128(env, i128::from(values.len()));
+    Some(mul_div_floor(env, &sum, &I256::from_i32(env, 1), &count))
+}
+
+fn median(env: &Env, values: &Vec<I256>) -> Option<I256> {
+    let mut sorted: alloc::vec::Vec<I256> = values.iter().collect();
+    sorted.sort();
+
+    let middle = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(sorted[middle].clone()),
+        _ => Some(mul_div_floor(
+            env,
+            &sorted[middle - 1].add(&sorted[middle]),
+            &I256::from_i32(env, 1),
+            &I256::from_i32(env, 2),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::DECIMALS;
     use soroban_sdk::vec;
 
     #[test]
@@ -120,7 +321,14 @@ mod tests {
         let env = Env::default();
 
         let ngq = NGQ::new(&env);
-        assert_eq!(ngq, NGQ { layers: vec![&env] });
+        assert_eq!(
+            ngq,
+            NGQ {
+                layers: vec![&env],
+                layer_weights: Map::new(&env),
+                combiner: LayerCombiner::Sum
+            }
+        );
     }
 
     #[test]
@@ -202,4 +410,355 @@ mod tests {
             I256::from_i128(&env, 12)
         );
     }
+
+    fn sample_result(env: &Env) -> (String, String, Map<String, Vec<I256>>) {
+        let user1 = String::from_str(env, "user1");
+        let user2 = String::from_str(env, "user2");
+
+        let mut result: Map<String, Vec<I256>> = Map::new(env);
+        result.set(
+            user1.clone(),
+            vec![
+                env,
+                I256::from_i128(env, 3 * DECIMALS),
+                I256::from_i128(env, -DECIMALS),
+                I256::from_i128(env, 2 * DECIMALS),
+            ],
+        );
+        result.set(
+            user2.clone(),
+            vec![
+                env,
+                I256::from_i128(env, 4 * DECIMALS),
+                I256::from_i128(env, DECIMALS),
+                I256::from_i128(env, 8 * DECIMALS),
+                I256::from_i128(env, 2 * DECIMALS),
+            ],
+        );
+        (user1, user2, result)
+    }
+
+    #[test]
+    fn aggregate_max() {
+        let env = Env::default();
+        let (user1, user2, result) = sample_result(&env);
+
+        let aggregated = aggregate_result(
+            &env,
+            result,
+            LayerAggregator::Max,
+            I256::from_i128(&env, DECIMALS),
+        );
+        assert_eq!(
+            aggregated.get(user1).unwrap(),
+            I256::from_i128(&env, 3 * DECIMALS)
+        );
+        assert_eq!(
+            aggregated.get(user2).unwrap(),
+            I256::from_i128(&env, 8 * DECIMALS)
+        );
+    }
+
+    #[test]
+    fn aggregate_min() {
+        let env = Env::default();
+        let (user1, user2, result) = sample_result(&env);
+
+        let aggregated = aggregate_result(
+            &env,
+            result,
+            LayerAggregator::Min,
+            I256::from_i128(&env, DECIMALS),
+        );
+        assert_eq!(
+            aggregated.get(user1).unwrap(),
+            I256::from_i128(&env, -DECIMALS)
+        );
+        assert_eq!(
+            aggregated.get(user2).unwrap(),
+            I256::from_i128(&env, DECIMALS)
+        );
+    }
+
+    #[test]
+    fn aggregate_mean() {
+        let env = Env::default();
+        let (user1, user2, result) = sample_result(&env);
+
+        let aggregated = aggregate_result(
+            &env,
+            result,
+            LayerAggregator::Mean,
+            I256::from_i128(&env, DECIMALS),
+        );
+        // 4/3, rounded down
+        assert_eq!(
+            aggregated.get(user1).unwrap(),
+            I256::from_i128(&env, 1_333_333_333_333_333_333)
+        );
+        assert_eq!(
+            aggregated.get(user2).unwrap(),
+            I256::from_i128(&env, 15 * DECIMALS / 4)
+        );
+    }
+
+    #[test]
+    fn aggregate_mean_rounds_down_negative() {
+        let env = Env::default();
+
+        let user1 = String::from_str(&env, "user1");
+        let mut result: Map<String, Vec<I256>> = Map::new(&env);
+        result.set(
+            user1.clone(),
+            vec![
+                &env,
+                I256::from_i128(&env, -1),
+                I256::from_i128(&env, 0),
+                I256::from_i128(&env, 0),
+            ],
+        );
+
+        let aggregated = aggregate_result(
+            &env,
+            result,
+            LayerAggregator::Mean,
+            I256::from_i128(&env, 1),
+        );
+        assert_eq!(aggregated.get(user1).unwrap(), I256::from_i128(&env, -1));
+    }
+
+    #[test]
+    fn aggregate_median() {
+        let env = Env::default();
+        let (user1, user2, result) = sample_result(&env);
+
+        let aggregated = aggregate_result(
+            &env,
+            result,
+            LayerAggregator::Median,
+            I256::from_i128(&env, DECIMALS),
+        );
+        assert_eq!(
+            aggregated.get(user1).unwrap(),
+            I256::from_i128(&env, 2 * DECIMALS)
+        );
+        // mean of 2 and 4
+        assert_eq!(
+            aggregated.get(user2).unwrap(),
+            I256::from_i128(&env, 3 * DECIMALS)
+        );
+    }
+
+    #[test]
+    fn aggregate_empty_statistics() {
+        let env = Env::default();
+
+        let user1 = String::from_str(&env, "user1");
+
+        for aggregator in [
+            LayerAggregator::Max,
+            LayerAggregator::Min,
+            LayerAggregator::Mean,
+            LayerAggregator::Median,
+        ] {
+            let mut result: Map<String, Vec<I256>> = Map::new(&env);
+            result.set(user1.clone(), vec![&env]);
+
+            let aggregated =
+                aggregate_result(&env, result, aggregator, I256::from_i128(&env, DECIMALS));
+            assert_eq!(
+                aggregated.get(user1.clone()).unwrap(),
+                I256::from_i32(&env, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn ordering_layers() {
+        let env = Env::default();
+
+        let trust = String::from_str(&env, "trust");
+        let reputation = String::from_str(&env, "reputation");
+        let history = String::from_str(&env, "history");
+        let combined = String::from_str(&env, "combined");
+        let layers = vec![
+            &env,
+            combined.clone(),
+            trust.clone(),
+            history.clone(),
+            reputation.clone(),
+        ];
+
+        let mut inputs = Map::new(&env);
+        inputs.set(
+            combined.clone(),
+            vec![&env, reputation.clone(), history.clone()],
+        );
+        inputs.set(reputation.clone(), vec![&env, trust.clone()]);
+
+        assert_eq!(
+            evaluation_order(&env, &layers, &inputs),
+            Ok(vec![
+                &env,
+                trust.clone(),
+                history.clone(),
+                reputation.clone(),
+                combined.clone()
+            ])
+        );
+        assert_eq!(output_layers(&env, &layers, &inputs), vec![&env, combined]);
+    }
+
+    #[test]
+    fn ordering_flat_layers() {
+        let env = Env::default();
+
+        let layers = vec![
+            &env,
+            String::from_str(&env, "0"),
+            String::from_str(&env, "1"),
+        ];
+
+        assert_eq!(
+            evaluation_order(&env, &layers, &Map::new(&env)),
+            Ok(layers.clone())
+        );
+        assert_eq!(output_layers(&env, &layers, &Map::new(&env)), layers);
+    }
+
+    #[test]
+    fn ordering_cyclic_layers() {
+        let env = Env::default();
+
+        let layer1 = String::from_str(&env, "1");
+        let layer2 = String::from_str(&env, "2");
+        let layer3 = String::from_str(&env, "3");
+        let layers = vec![&env, layer1.clone(), layer2.clone(), layer3.clone()];
+
+        let mut inputs = Map::new(&env);
+        inputs.set(layer1.clone(), vec![&env, layer3.clone()]);
+        inputs.set(layer3.clone(), vec![&env, layer2.clone()]);
+        inputs.set(layer2.clone(), vec![&env, layer1.clone()]);
+
+        assert_eq!(
+            evaluation_order(&env, &layers, &inputs),
+            Err(VotingSystemError::CyclicLayers)
+        );
+    }
+
+    fn layer_results(env: &Env) -> (String, String, Vec<(I256, Map<String, I256>)>) {
+        let user1 = String::from_str(env, "user1");
+        let user2 = String::from_str(env, "user2");
+
+        let mut layer1 = Map::new(env);
+        layer1.set(user1.clone(), I256::from_i128(env, 2 * DECIMALS));
+        layer1.set(user2.clone(), I256::from_i128(env, 4 * DECIMALS));
+        let mut layer2 = Map::new(env);
+        layer2.set(user1.clone(), I256::from_i128(env, 5 * DECIMALS));
+
+        (
+            user1,
+            user2,
+            vec![
+                env,
+                (I256::from_i128(env, 3 * DECIMALS), layer1),
+                (I256::from_i128(env, DECIMALS / 2), layer2),
+            ],
+        )
+    }
+
+    #[test]
+    fn combine_sum() {
+        let env = Env::default();
+        let (user1, user2, results) = layer_results(&env);
+
+        let combined = combine_results(
+            &env,
+            results,
+            LayerCombiner::Sum,
+            I256::from_i128(&env, DECIMALS),
+        );
+        // 3 * 2 + 0.5 * 5
+        assert_eq!(
+            combined.get(user1).unwrap(),
+            I256::from_i128(&env, 85 * DECIMALS / 10)
+        );
+        assert_eq!(
+            combined.get(user2).unwrap(),
+            I256::from_i128(&env, 12 * DECIMALS)
+        );
+    }
+
+    #[test]
+    fn combine_product() {
+        let env = Env::default();
+        let (user1, user2, results) = layer_results(&env);
+
+        let combined = combine_results(
+            &env,
+            results,
+            LayerCombiner::Product,
+            I256::from_i128(&env, DECIMALS),
+        );
+        // (3 * 2) * (0.5 * 5)
+        assert_eq!(
+            combined.get(user1).unwrap(),
+            I256::from_i128(&env, 15 * DECIMALS)
+        );
+        assert_eq!(
+            combined.get(user2).unwrap(),
+            I256::from_i128(&env, 12 * DECIMALS)
+        );
+    }
+
+    #[test]
+    fn combine_weighted_mean() {
+        let env = Env::default();
+        let (user1, user2, results) = layer_results(&env);
+
+        let combined = combine_results(
+            &env,
+            results,
+            LayerCombiner::WeightedMean,
+            I256::from_i128(&env, DECIMALS),
+        );
+        // (3 * 2 + 0.5 * 5) / 3.5
+        assert_eq!(
+            combined.get(user1).unwrap(),
+            I256::from_i128(&env, 2_428_571_428_571_428_571)
+        );
+        assert_eq!(
+            combined.get(user2).unwrap(),
+            I256::from_i128(&env, 4 * DECIMALS)
+        );
+    }
+
+    #[test]
+    fn combine_with_default_weights() {
+        let env = Env::default();
+
+        let ngq = NGQ::new(&env);
+        let decimals = I256::from_i128(&env, DECIMALS);
+        let user1 = String::from_str(&env, "user1");
+        let mut result = Map::new(&env);
+        result.set(user1.clone(), I256::from_i128(&env, 7));
+
+        let combined = combine_results(
+            &env,
+            vec![
+                &env,
+                (
+                    ngq.layer_weight(&String::from_str(&env, "0"), &decimals),
+                    result.clone(),
+                ),
+                (
+                    ngq.layer_weight(&String::from_str(&env, "1"), &decimals),
+                    result,
+                ),
+            ],
+            ngq.combiner,
+            decimals,
+        );
+        assert_eq!(combined.get(user1).unwrap(), I256::from_i128(&env, 14));
+    }
 }
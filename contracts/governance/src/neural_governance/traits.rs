@@ -1,10 +1,14 @@
-use crate::neural_governance::{Layer, LayerAggregator, Neuron, NGQ};
-use crate::types::VotingSystemError;
-use soroban_sdk::{Env, Map, String, Vec, I256};
+use crate::neural_governance::{
+    Layer, LayerAggregator, LayerCombiner, NeuralGovernanceRound, Neuron, NGQ,
+};
+use crate::types::{VotingPowerBreakdown, VotingPowerPolicy, VotingSystemError};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Vec, I256};
 
 pub trait Governance {
     /// Add a new layer to the contract.
     ///
+    /// Neurons are identified by their names, which have to be unique within the layer.
+    ///
     /// # Arguments
     ///
     /// * `raw_neurons`: tuples of neuron names and their respective weights.
@@ -17,6 +21,8 @@ pub trait Governance {
 
     /// Remove a layer from the contract
     ///
+    /// Layers used as inputs of other layers can't be removed.
+    ///
     /// # Arguments
     ///
     /// * `layer_id`: ID of the layer to remove
@@ -24,6 +30,10 @@ pub trait Governance {
 
     /// Update an existing layer
     ///
+    /// Neurons keep their IDs, and so their results, as long as their names don't change. Neurons
+    /// missing from `raw_neurons` are removed. Results of the active round of layers that still
+    /// identify neurons by their positions are moved to the neuron names.
+    ///
     /// # Arguments
     ///
     /// * `layer_id`: ID of the layer to update
@@ -36,6 +46,27 @@ pub trait Governance {
         layer_aggregator: LayerAggregator,
     ) -> Result<(), VotingSystemError>;
 
+    /// Add a neuron to an existing layer without changing IDs of the other neurons.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer_id`: ID of the layer
+    /// * `name`: name of the neuron, used as its ID
+    /// * `weight`: weight of the neuron
+    fn add_neuron(
+        env: Env,
+        layer_id: String,
+        name: String,
+        weight: I256,
+    ) -> Result<(), VotingSystemError>;
+
+    /// Remove a neuron from a layer without changing IDs of the other neurons.
+    fn remove_neuron(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+    ) -> Result<(), VotingSystemError>;
+
     // TODO docs
     fn get_layer(env: Env, layer_id: String) -> Result<Layer, VotingSystemError>;
 
@@ -62,18 +93,196 @@ pub trait Governance {
     ) -> Result<Map<String, I256>, VotingSystemError>;
 
     /// Set neuron result for the active round.
-    fn set_neuron_result(env: Env, layer_id: String, neuron_id: String, result: Map<String, I256>);
+    ///
+    /// Neuron results can be changed until the tally is finalized. Changing a neuron result after
+    /// the voting powers are calculated drops the powers and the provisional tally results and
+    /// moves the round back to `VotingClosed`, as does every other change of neuron results.
+    /// Replaces a committed Merkle root of the neuron result, if any.
+    fn set_neuron_result(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+        result: Map<String, I256>,
+    ) -> Result<(), VotingSystemError>;
+
+    /// Upload another chunk of the neuron result for the active round.
+    ///
+    /// Neuron results too large to upload with `set_neuron_result` at once can be uploaded in
+    /// chunks. Each chunk is stored separately and the result is used only after
+    /// `seal_neuron_result` is called. A sealed upload can be replaced with `set_neuron_result` or
+    /// discarded with `reset_neuron_result_upload`.
+    ///
+    /// Returns the number of chunks uploaded so far.
+    fn append_neuron_result_chunk(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+        result: Map<String, I256>,
+    ) -> Result<u32, VotingSystemError>;
+
+    /// Complete a chunked upload of the neuron result for the active round.
+    fn seal_neuron_result(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+    ) -> Result<(), VotingSystemError>;
+
+    /// Discard a chunked upload of the neuron result for the active round, sealed or not, so it can
+    /// be uploaded again from the first chunk.
+    fn reset_neuron_result_upload(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+    ) -> Result<(), VotingSystemError>;
+
+    /// Commit a Merkle root of the neuron result for the active round instead of uploading it.
+    ///
+    /// Voters then claim their values with `claim_voting_power`. Committing a root clears the
+    /// neuron result, including values claimed against a previous root.
+    ///
+    /// # Arguments
+    ///
+    /// * `root`: root of a tree with leaves `sha256(0x00 || xdr((voter_id, value)))` and nodes
+    ///   `sha256(0x01 || min(a, b) || max(a, b))`.
+    fn set_neuron_result_root(
+        env: Env,
+        layer_id: String,
+        neuron_id: String,
+        root: BytesN<32>,
+    ) -> Result<(), VotingSystemError>;
+
+    /// Get the committed Merkle root of the neuron result for a specific round.
+    fn get_neuron_result_root(
+        env: &Env,
+        layer_id: String,
+        neuron_id: String,
+        round: u32,
+    ) -> Result<BytesN<32>, VotingSystemError>;
+
+    /// Claim the value of the voter in a committed neuron result of the active round.
+    ///
+    /// The value is verified against the committed Merkle root and added to the neuron result,
+    /// so it's used by `calculate_voting_powers`. Values can be claimed only until the voting
+    /// powers of the round are calculated.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: value of the voter in the neuron result.
+    /// * `proof`: sibling hashes on the path from the leaf of the voter to the root.
+    fn claim_voting_power(
+        env: Env,
+        voter: Address,
+        layer_id: String,
+        neuron_id: String,
+        value: I256,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), VotingSystemError>;
 
     /// Get a map of user public keys and their voting powers for a layer for the active round.
     fn get_layer_result(env: Env, layer_id: String)
         -> Result<Map<String, I256>, VotingSystemError>;
 
+    /// Use outputs of other layers as inputs of a layer.
+    ///
+    /// Results of the input layers are aggregated together with the neuron results of the layer.
+    /// Layers consumed by another layer no longer contribute to the final voting powers directly,
+    /// so a network like `(trust + reputation) * history` ends with a single layer giving the final
+    /// voting power.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer_id`: ID of the consuming layer
+    /// * `inputs`: IDs of the layers whose outputs are consumed, replacing previous inputs. Inputs
+    ///   must not form a cycle.
+    fn set_layer_inputs(
+        env: Env,
+        layer_id: String,
+        inputs: Vec<String>,
+    ) -> Result<(), VotingSystemError>;
+
+    /// Get IDs of the layers whose outputs are inputs of a layer.
+    fn get_layer_inputs(env: Env, layer_id: String) -> Result<Vec<String>, VotingSystemError>;
+
+    /// Set a weight of a layer output used when combining final voting powers.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer_id`: ID of the layer
+    /// * `weight`: non-negative fixed-point number with `DECIMALS`, layers have a weight of 1 by
+    ///   default
+    fn set_layer_weight(env: Env, layer_id: String, weight: I256) -> Result<(), VotingSystemError>;
+
+    /// Set a function combining outputs of the final layers into voting powers.
+    fn set_layer_combiner(env: Env, combiner: LayerCombiner) -> Result<(), VotingSystemError>;
+
     /// Calculate final voting powers for the active round and write them to contract storage.
+    ///
+    /// The neural governance configuration used for the calculation is stored for the round, see
+    /// `get_neural_governance_round`.
+    ///
+    /// Final voting powers combine weighted results of layers that are not inputs of other layers
+    /// using the configured `LayerCombiner`.
+    ///
+    /// Closes the voting window and moves the round to the `PowersCalculated` phase. Voting powers
+    /// can be recalculated until the tally is finalized. Commit-reveal rounds have to be in the
+    /// `Reveal` phase or later, so committed votes can't be skipped.
     fn calculate_voting_powers(env: Env) -> Result<(), VotingSystemError>;
 
+    /// Preview voting powers of the active round without storing them.
+    ///
+    /// Uses the same calculation as `calculate_voting_powers` and doesn't require a particular
+    /// round phase. Neuron results read by the simulation don't get their lifetime extended.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_override`: configuration to calculate with instead of the current one, e.g. with
+    ///   changed layers or weights. Defaults to the current configuration.
+    fn simulate_voting_powers(
+        env: Env,
+        config_override: Option<NeuralGovernanceRound>,
+    ) -> Result<Map<String, I256>, VotingSystemError>;
+
+    /// Set policies applied to voting powers calculated by `calculate_voting_powers`.
+    ///
+    /// Voting powers are capped at `max_power` and at `max_share` of the total voting power, raised
+    /// to `min_power` and finally normalized. Policies apply to voting powers calculated after they
+    /// are set.
+    fn set_voting_power_policy(
+        env: Env,
+        policy: VotingPowerPolicy,
+    ) -> Result<(), VotingSystemError>;
+
+    /// Get policies applied to calculated voting powers.
+    fn get_voting_power_policy(env: Env) -> VotingPowerPolicy;
+
+    /// Explain the voting power of a voter in a round.
+    ///
+    /// Recomputes raw and weighted values of every neuron and aggregated values of every layer the
+    /// voter has a result in, using the same calculation as `calculate_voting_powers` and the
+    /// configuration stored for the round.
+    ///
+    /// # Arguments
+    ///
+    /// * `round`: round with calculated voting powers
+    /// * `voter_id`: ID of the voter
+    fn get_voting_power_breakdown(
+        env: Env,
+        round: u32,
+        voter_id: String,
+    ) -> Result<VotingPowerBreakdown, VotingSystemError>;
+
     /// Get a map of user public keys and their voting powers for whole governance for the active round.
     fn get_voting_powers(env: Env) -> Result<Map<String, I256>, VotingSystemError>;
 
     /// Get a representation of the current NGQ setup.
     fn get_neural_governance(env: &Env) -> Result<NGQ, VotingSystemError>;
+
+    /// Get the neural governance configuration used to calculate voting powers of a round.
+    ///
+    /// Holds the layers, neurons, layer inputs and voting power policy as they were when voting
+    /// powers of the round were last calculated.
+    fn get_neural_governance_round(
+        env: &Env,
+        round: u32,
+    ) -> Result<NeuralGovernanceRound, VotingSystemError>;
 }
@@ -0,0 +1,272 @@
+use soroban_sdk::{Env, Map, String, I256};
+
+use crate::fixed_mul_floor::mul_div_floor;
+use crate::types::{PowerNormalization, VotingPowerPolicy, VotingSystemError};
+use crate::ContractResult;
+
+pub(crate) fn validate_policy(
+    env: &Env,
+    policy: &VotingPowerPolicy,
+    decimals: &I256,
+) -> ContractResult<()> {
+    let zero = I256::from_i32(env, 0);
+    let negative = |value: &Option<I256>| value.as_ref().is_some_and(|value| *value < zero);
+
+    if negative(&policy.max_power) || negative(&policy.min_power) {
+        return Err(VotingSystemError::InvalidVotingPowerPolicy);
+    }
+    if policy
+        .max_share
+        .as_ref()
+        .is_some_and(|max_share| *max_share <= zero || max_share > decimals)
+    {
+        return Err(VotingSystemError::InvalidVotingPowerPolicy);
+    }
+    if let (Some(min_power), Some(max_power)) = (&policy.min_power, &policy.max_power) {
+        if min_power > max_power {
+            return Err(VotingSystemError::InvalidVotingPowerPolicy);
+        }
+    }
+    // min-max normalization maps the lowest voting power to 0, undoing the floor
+    if policy.min_power.is_some() && policy.normalization == PowerNormalization::MinMax {
+        return Err(VotingSystemError::InvalidVotingPowerPolicy);
+    }
+    Ok(())
+}
+
+/// Apply the voting power policy to aggregated voting powers.
+///
+/// Voting powers are capped at `max_power` and at `max_share` of the total voting power, raised to
+/// `min_power` and finally normalized. The floor is applied after the caps, so it takes precedence
+/// over `max_share`.
+pub(crate) fn apply_policy(
+    env: &Env,
+    voting_powers: Map<String, I256>,
+    policy: &VotingPowerPolicy,
+    decimals: &I256,
+) -> Map<String, I256> {
+    let zero = I256::from_i32(env, 0);
+    let total = voting_powers
+        .values()
+        .iter()
+        .fold(zero.clone(), |total, power| total.add(&power));
+    let share_cap = policy.max_share.as_ref().map(|max_share| {
+        if total > zero {
+            mul_div_floor(env, &total, max_share, decimals)
+        } else {
+            zero.clone()
+        }
+    });
+
+    let mut limited = Map::new(env);
+    for (voter_id, power) in voting_powers {
+        let mut power = power;
+        for cap in [policy.max_power.as_ref(), share_cap.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            if power > *cap {
+                power = cap.clone();
+            }
+        }
+        if let Some(min_power) = &policy.min_power {
+            if power < *min_power {
+                power = min_power.clone();
+            }
+        }
+        limited.set(voter_id, power);
+    }
+
+    match policy.normalization {
+        PowerNormalization::Disabled => limited,
+        PowerNormalization::MinMax => normalize_min_max(env, limited, decimals),
+        PowerNormalization::SumToOne => normalize_sum(env, limited, decimals),
+    }
+}
+
+/// Voters with equal voting powers all get a voting power of 1.
+fn normalize_min_max(
+    env: &Env,
+    voting_powers: Map<String, I256>,
+    decimals: &I256,
+) -> Map<String, I256> {
+    let values = voting_powers.values();
+    let Some(first) = values.first() else {
+        return voting_powers;
+    };
+    let (min, max) = values
+        .iter()
+        .fold((first.clone(), first), |(min, max), power| {
+            let min = if power < min { power.clone() } else { min };
+            let max = if power > max { power } else { max };
+            (min, max)
+        });
+    let range = max.sub(&min);
+
+    let mut normalized = Map::new(env);
+    for (voter_id, power) in voting_powers {
+        let power = if range == I256::from_i32(env, 0) {
+            decimals.clone()
+        } else {
+            mul_div_floor(env, &power.sub(&min), decimals, &range)
+        };
+        normalized.set(voter_id, power);
+    }
+    normalized
+}
+
+/// Voting powers are set to 0 if the total voting power is not positive.
+fn normalize_sum(
+    env: &Env,
+    voting_powers: Map<String, I256>,
+    decimals: &I256,
+) -> Map<String, I256> {
+    let zero = I256::from_i32(env, 0);
+    let total = voting_powers
+        .values()
+        .iter()
+        .fold(zero.clone(), |total, power| total.add(&power));
+
+    let mut normalized = Map::new(env);
+    for (voter_id, power) in voting_powers {
+        let power = if total > zero {
+            mul_div_floor(env, &power, decimals, &total)
+        } else {
+            zero.clone()
+        };
+        normalized.set(voter_id, power);
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DECIMALS;
+
+    fn powers(env: &Env, values: &[i128]) -> Map<String, I256> {
+        let mut powers = Map::new(env);
+        for (i, value) in values.iter().enumerate() {
+            powers.set(
+                String::from_str(env, ["a", "b", "c", "d"][i]),
+                I256::from_i128(env, *value * DECIMALS),
+            );
+        }
+        powers
+    }
+
+    fn apply(env: &Env, values: &[i128], policy: &VotingPowerPolicy) -> Map<String, I256> {
+        apply_policy(
+            env,
+            powers(env, values),
+            policy,
+            &I256::from_i128(env, DECIMALS),
+        )
+    }
+
+    #[test]
+    fn empty_policy() {
+        let env = Env::default();
+
+        assert_eq!(
+            apply(&env, &[1, 5, 10], &VotingPowerPolicy::default()),
+            powers(&env, &[1, 5, 10])
+        );
+    }
+
+    #[test]
+    fn capping_voting_powers() {
+        let env = Env::default();
+
+        let mut absolute = VotingPowerPolicy::default();
+        absolute.max_power = Some(I256::from_i128(&env, 4 * DECIMALS));
+        assert_eq!(
+            apply(&env, &[1, 5, 10], &absolute),
+            powers(&env, &[1, 4, 4])
+        );
+
+        // 25% of the total power of 16
+        let mut share = VotingPowerPolicy::default();
+        share.max_share = Some(I256::from_i128(&env, DECIMALS / 4));
+        assert_eq!(apply(&env, &[1, 5, 10], &share), powers(&env, &[1, 4, 4]));
+    }
+
+    #[test]
+    fn raising_voting_powers_to_floor() {
+        let env = Env::default();
+
+        let mut floor = VotingPowerPolicy::default();
+        floor.min_power = Some(I256::from_i128(&env, 2 * DECIMALS));
+        floor.max_share = Some(I256::from_i128(&env, DECIMALS / 100));
+        // the floor takes precedence over the share cap
+        assert_eq!(apply(&env, &[1, 5, 10], &floor), powers(&env, &[2, 2, 2]));
+    }
+
+    #[test]
+    fn normalizing_voting_powers() {
+        let env = Env::default();
+
+        let mut min_max = VotingPowerPolicy::default();
+        min_max.normalization = PowerNormalization::MinMax;
+        let normalized = apply(&env, &[2, 4, 10], &min_max);
+        assert_eq!(
+            normalized.values().first().unwrap(),
+            I256::from_i32(&env, 0)
+        );
+        assert_eq!(
+            normalized.values().get(1).unwrap(),
+            I256::from_i128(&env, DECIMALS / 4)
+        );
+        assert_eq!(
+            normalized.values().last().unwrap(),
+            I256::from_i128(&env, DECIMALS)
+        );
+        assert_eq!(apply(&env, &[3, 3], &min_max), powers(&env, &[1, 1]));
+
+        let mut sum = VotingPowerPolicy::default();
+        sum.normalization = PowerNormalization::SumToOne;
+        let normalized = apply(&env, &[1, 3], &sum);
+        assert_eq!(
+            normalized.values().first().unwrap(),
+            I256::from_i128(&env, DECIMALS / 4)
+        );
+        assert_eq!(
+            normalized.values().last().unwrap(),
+            I256::from_i128(&env, 3 * DECIMALS / 4)
+        );
+        assert_eq!(apply(&env, &[0, 0], &sum), powers(&env, &[0, 0]));
+    }
+
+    #[test]
+    fn validating_policy() {
+        let env = Env::default();
+        let decimals = I256::from_i128(&env, DECIMALS);
+
+        assert_eq!(
+            validate_policy(&env, &VotingPowerPolicy::default(), &decimals),
+            Ok(())
+        );
+
+        let mut floor_with_sum = VotingPowerPolicy::default();
+        floor_with_sum.min_power = Some(I256::from_i128(&env, 2 * DECIMALS));
+        floor_with_sum.normalization = PowerNormalization::SumToOne;
+        assert_eq!(validate_policy(&env, &floor_with_sum, &decimals), Ok(()));
+
+        let mut negative = VotingPowerPolicy::default();
+        negative.min_power = Some(I256::from_i32(&env, -1));
+        let mut over_total = VotingPowerPolicy::default();
+        over_total.max_share = Some(I256::from_i128(&env, DECIMALS + 1));
+        let mut floor_above_cap = VotingPowerPolicy::default();
+        floor_above_cap.min_power = Some(I256::from_i32(&env, 2));
+        floor_above_cap.max_power = Some(I256::from_i32(&env, 1));
+        let mut floor_with_min_max = VotingPowerPolicy::default();
+        floor_with_min_max.min_power = Some(I256::from_i128(&env, 2 * DECIMALS));
+        floor_with_min_max.normalization = PowerNormalization::MinMax;
+        for invalid in [negative, over_total, floor_above_cap, floor_with_min_max] {
+            assert_eq!(
+                validate_policy(&env, &invalid, &decimals),
+                Err(VotingSystemError::InvalidVotingPowerPolicy)
+            );
+        }
+    }
+}
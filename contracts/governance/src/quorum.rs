@@ -0,0 +1,224 @@
+use soroban_sdk::{Env, Map, String, Vec};
+
+use crate::storage::read_delegatees;
+use crate::types::{Vote, VotingSystemError};
+use crate::ContractResult;
+
+/// Number of delegatees a voter has to select for each category.
+pub const SMALLEST_DEFINED_QUORUM_SIZE: u32 = 7;
+/// Upper bound of delegatees for each category, keeps the tally within resource limits.
+pub const MAX_QUORUM_SIZE: u32 = 10;
+/// Minimal number of delegatees that voted `Yes` or `No` to resolve a delegated vote.
+pub const MIN_QUORUM_SIZE: u32 = 5;
+/// Fraction of `Yes` votes required for a delegated vote to resolve to `Yes`, in thousandths.
+pub const THRESHOLD: u32 = 667;
+const THRESHOLD_DENOMINATOR: u32 = 1000;
+
+pub(crate) fn validate_delegatees(
+    voter_id: &String,
+    delegatees: &Vec<String>,
+) -> ContractResult<()> {
+    if delegatees.len() < SMALLEST_DEFINED_QUORUM_SIZE {
+        return Err(VotingSystemError::NotEnoughDelegatees);
+    }
+    if delegatees.len() > MAX_QUORUM_SIZE {
+        return Err(VotingSystemError::TooManyDelegatees);
+    }
+
+    for (index, delegatee) in delegatees.iter().enumerate() {
+        if delegatee == *voter_id
+            || delegatees
+                .iter()
+                .skip(index + 1)
+                .any(|other| other == delegatee)
+        {
+            return Err(VotingSystemError::UnexpectedValue);
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that every voter with a `Delegate` vote for a submission has delegatees for its category.
+pub(crate) fn require_delegatees(
+    env: &Env,
+    round: u32,
+    category: &String,
+    submission_votes: &Map<String, Vote>,
+) -> ContractResult<()> {
+    for (voter_id, vote) in submission_votes.iter() {
+        if vote == Vote::Delegate {
+            read_delegatees(env, &voter_id, category, round)?;
+        }
+    }
+    Ok(())
+}
+
+/// Resolve every `Delegate` vote for a submission into the consensus of the voter's quorum.
+pub(crate) fn normalize_votes(
+    env: &Env,
+    round: u32,
+    category: &String,
+    submission_votes: &Map<String, Vote>,
+) -> ContractResult<Map<String, Vote>> {
+    let mut normalized = Map::new(env);
+
+    for (voter_id, vote) in submission_votes.iter() {
+        let vote = if vote == Vote::Delegate {
+            let delegatees = read_delegatees(env, &voter_id, category, round)?;
+            calculate_quorum_consensus(&delegatees, submission_votes)?
+        } else {
+            vote
+        };
+        normalized.set(voter_id, vote);
+    }
+
+    Ok(normalized)
+}
+
+pub(crate) fn calculate_quorum_consensus(
+    delegatees: &Vec<String>,
+    submission_votes: &Map<String, Vote>,
+) -> ContractResult<Vote> {
+    if delegatees.len() < SMALLEST_DEFINED_QUORUM_SIZE {
+        return Err(VotingSystemError::NotEnoughDelegatees);
+    }
+
+    let mut votes_yes: u32 = 0;
+    let mut votes_no: u32 = 0;
+    for delegatee in delegatees.iter() {
+        match submission_votes.get(delegatee) {
+            Some(Vote::Yes) => votes_yes += 1,
+            Some(Vote::No) => votes_no += 1,
+            _ => (),
+        }
+    }
+
+    if votes_yes + votes_no < MIN_QUORUM_SIZE {
+        return Ok(Vote::Abstain);
+    }
+
+    let yes_share = votes_yes
+        .checked_mul(THRESHOLD_DENOMINATOR)
+        .ok_or(VotingSystemError::DelegationCalculationFailed)?;
+    let threshold = (votes_yes + votes_no)
+        .checked_mul(THRESHOLD)
+        .ok_or(VotingSystemError::DelegationCalculationFailed)?;
+
+    Ok(if yes_share > threshold {
+        Vote::Yes
+    } else {
+        Vote::No
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn delegatees(env: &Env, count: u32) -> Vec<String> {
+        let mut delegatees = Vec::new(env);
+        for i in 0..count {
+            delegatees.push_back(String::from_str(env, format!("del{i}").as_str()));
+        }
+        delegatees
+    }
+
+    fn votes(env: &Env, delegatees: &Vec<String>, yes: u32, no: u32) -> Map<String, Vote> {
+        let mut votes = Map::new(env);
+        for (i, delegatee) in delegatees.iter().enumerate() {
+            let i = u32::try_from(i).unwrap();
+            if i < yes {
+                votes.set(delegatee, Vote::Yes);
+            } else if i < yes + no {
+                votes.set(delegatee, Vote::No);
+            }
+        }
+        votes
+    }
+
+    #[test]
+    fn calculate_quorum_consensus_yes() {
+        let env = Env::default();
+
+        let delegatees = delegatees(&env, 7);
+        let submission_votes = votes(&env, &delegatees, 5, 2);
+
+        assert_eq!(
+            calculate_quorum_consensus(&delegatees, &submission_votes),
+            Ok(Vote::Yes)
+        );
+    }
+
+    #[test]
+    fn calculate_quorum_consensus_no() {
+        let env = Env::default();
+
+        // 4/7 is below the threshold
+        let delegatees = delegatees(&env, 7);
+        let submission_votes = votes(&env, &delegatees, 4, 3);
+
+        assert_eq!(
+            calculate_quorum_consensus(&delegatees, &submission_votes),
+            Ok(Vote::No)
+        );
+    }
+
+    #[test]
+    fn abstain_if_less_than_min_quorum_voted() {
+        let env = Env::default();
+
+        let delegatees = delegatees(&env, 7);
+        let submission_votes = votes(&env, &delegatees, 4, 0);
+
+        assert_eq!(
+            calculate_quorum_consensus(&delegatees, &submission_votes),
+            Ok(Vote::Abstain)
+        );
+    }
+
+    #[test]
+    fn quorum_size_too_small() {
+        let env = Env::default();
+
+        let delegatees = delegatees(&env, 6);
+        let submission_votes = votes(&env, &delegatees, 6, 0);
+
+        assert_eq!(
+            calculate_quorum_consensus(&delegatees, &submission_votes),
+            Err(VotingSystemError::NotEnoughDelegatees)
+        );
+    }
+
+    #[test]
+    fn validating_delegatees() {
+        let env = Env::default();
+
+        let voter = String::from_str(&env, "voter");
+
+        assert_eq!(validate_delegatees(&voter, &delegatees(&env, 7)), Ok(()));
+        assert_eq!(
+            validate_delegatees(&voter, &delegatees(&env, 6)),
+            Err(VotingSystemError::NotEnoughDelegatees)
+        );
+        assert_eq!(
+            validate_delegatees(&voter, &delegatees(&env, 11)),
+            Err(VotingSystemError::TooManyDelegatees)
+        );
+
+        let mut with_duplicate = delegatees(&env, 7);
+        with_duplicate.push_back(String::from_str(&env, "del0"));
+        assert_eq!(
+            validate_delegatees(&voter, &with_duplicate),
+            Err(VotingSystemError::UnexpectedValue)
+        );
+
+        let mut with_self = delegatees(&env, 7);
+        with_self.push_back(voter.clone());
+        assert_eq!(
+            validate_delegatees(&voter, &with_self),
+            Err(VotingSystemError::UnexpectedValue)
+        );
+    }
+}
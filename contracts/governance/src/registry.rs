@@ -0,0 +1,64 @@
+use soroban_sdk::{Env, IntoVal, Map, String, TryFromVal, Val, Vec, I256};
+
+use crate::storage::{is_eligible_voter, read_voter_registry};
+use crate::types::{Vote, VotingSystemError};
+use crate::ContractResult;
+
+/// Check that the voters are registered as eligible in the round.
+///
+/// Rounds without a voter registry accept every voter.
+pub(crate) fn require_eligible_voters(
+    env: &Env,
+    round: u32,
+    voters: &Vec<String>,
+) -> ContractResult<()> {
+    if read_voter_registry(env, round).is_none() {
+        return Ok(());
+    }
+    for voter_id in voters.iter() {
+        if !is_eligible_voter(env, round, &voter_id) {
+            return Err(VotingSystemError::VoterNotEligible);
+        }
+    }
+    Ok(())
+}
+
+/// Drop voters that aren't registered as eligible in the round.
+///
+/// Data uploaded before the round got a registry and voters unregistered after their data was
+/// uploaded aren't rejected at upload, so the results are filtered again whenever they are used.
+/// Rounds without a voter registry keep every voter.
+pub(crate) fn retain_eligible_voters<V>(env: &Env, round: u32, values: &mut Map<String, V>)
+where
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if read_voter_registry(env, round).is_none() {
+        return;
+    }
+    for voter_id in values.keys() {
+        if !is_eligible_voter(env, round, &voter_id) {
+            values.remove(voter_id);
+        }
+    }
+}
+
+/// Give voters of a submission without a voting power zero voting power in rounds with a voter
+/// registry.
+///
+/// Registered voters don't have to have neuron results, so their votes count with zero power
+/// instead of failing the tally. Votes are expected to be filtered with `retain_eligible_voters`.
+pub(crate) fn add_missing_voting_powers(
+    env: &Env,
+    round: u32,
+    votes: &Map<String, Vote>,
+    voting_powers: &mut Map<String, I256>,
+) {
+    if read_voter_registry(env, round).is_none() {
+        return;
+    }
+    for voter_id in votes.keys() {
+        if !voting_powers.contains_key(voter_id.clone()) {
+            voting_powers.set(voter_id, I256::from_i32(env, 0));
+        }
+    }
+}
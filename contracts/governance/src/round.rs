@@ -0,0 +1,51 @@
+use soroban_sdk::Env;
+
+use crate::events::GovernanceEvents;
+use crate::storage::{
+    read_round_phase, remove_neural_governance_round, remove_tally_results, remove_voting_powers,
+    write_round_phase,
+};
+use crate::types::{RoundPhase, VotingSystemError};
+use crate::ContractResult;
+
+/// Check that the round is in one of the `allowed` phases.
+///
+/// Finalized rounds are read-only, so attempting to modify one fails with `RoundFinalized`.
+pub(crate) fn require_phase(
+    env: &Env,
+    round: u32,
+    allowed: &[RoundPhase],
+) -> ContractResult<RoundPhase> {
+    let phase = read_round_phase(env, round);
+    if allowed.contains(&phase) {
+        Ok(phase)
+    } else if phase == RoundPhase::Finalized {
+        Err(VotingSystemError::RoundFinalized)
+    } else {
+        Err(VotingSystemError::InvalidRoundPhase)
+    }
+}
+
+/// Move the round to `phase`, publishing an event if the phase changes.
+pub(crate) fn set_phase(env: &Env, round: u32, phase: RoundPhase) {
+    if read_round_phase(env, round) == phase {
+        return;
+    }
+    write_round_phase(env, round, phase);
+    GovernanceEvents::round_phase_changed(env, round, phase);
+}
+
+/// Drop the voting powers of a round whose neuron results changed after they were calculated.
+///
+/// The powers, the neural governance snapshot and the provisional tally results no longer match
+/// the neuron results, so the round moves back to `VotingClosed` and the powers have to be
+/// calculated again before tallying.
+pub(crate) fn reset_voting_powers(env: &Env, round: u32) {
+    if read_round_phase(env, round) != RoundPhase::PowersCalculated {
+        return;
+    }
+    remove_voting_powers(env, round);
+    remove_neural_governance_round(env, round);
+    remove_tally_results(env, round);
+    set_phase(env, round, RoundPhase::VotingClosed);
+}
@@ -0,0 +1,44 @@
+use soroban_sdk::xdr::FromXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+/// XDR prefix of an ed25519 account address: the `ScVal::Address` discriminant (18), followed by
+/// the `ScAddress::Account` (0) and `PublicKey::Ed25519` (0) discriminants. The 32 bytes of the
+/// public key follow.
+const ACCOUNT_ADDRESS_XDR_PREFIX: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Get the Stellar account address of an ed25519 public key.
+pub(crate) fn account_address(env: &Env, public_key: &BytesN<32>) -> Address {
+    let mut xdr = Bytes::from_array(env, &ACCOUNT_ADDRESS_XDR_PREFIX);
+    xdr.append(&public_key.clone().into());
+    Address::from_xdr(env, &xdr).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converting_public_keys_to_account_addresses() {
+        let env = Env::default();
+
+        assert_eq!(
+            account_address(&env, &BytesN::from_array(&env, &[0; 32])),
+            Address::from_str(
+                &env,
+                "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"
+            )
+        );
+
+        let mut public_key = [0; 32];
+        for (i, byte) in public_key.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        assert_eq!(
+            account_address(&env, &BytesN::from_array(&env, &public_key)),
+            Address::from_str(
+                &env,
+                "GAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB7JZX"
+            )
+        );
+    }
+}
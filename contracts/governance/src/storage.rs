@@ -1,20 +1,73 @@
-use soroban_sdk::{Env, Map, String, Vec, I256};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec, I256};
 
-use crate::neural_governance::{Layer, Neuron, NGQ};
+use crate::neural_governance::{Layer, NeuralGovernanceRound, Neuron, NGQ};
 use crate::storage::key_data::{
-    get_layer_key, get_neuron_key, get_neuron_result_key, get_submission_votes_key,
-    get_submissions_key, get_tally_results_key, get_voting_powers_key,
+    get_budgets_key, get_delegatees_key, get_eligible_voter_key, get_layer_inputs_key,
+    get_layer_key, get_neural_governance_round_key, get_neuron_claim_key, get_neuron_claimant_key,
+    get_neuron_claimants_count_key, get_neuron_key, get_neuron_result_chunk_key,
+    get_neuron_result_key, get_neuron_result_root_key, get_neuron_result_upload_key,
+    get_round_phase_key, get_submission_voter_key, get_submission_voters_count_key,
+    get_submission_votes_chunk_key, get_submission_votes_key, get_submission_votes_upload_key,
+    get_submissions_key, get_tally_results_key, get_tally_rules_key, get_vote_commitment_key,
+    get_vote_nonce_key, get_voter_registry_key, get_voter_vote_key, get_voting_mode_key,
+    get_voting_powers_key,
+};
+use crate::types::{
+    CategoryBudget, ChunkedUpload, RoundPhase, Submission, SubmissionCategory, TallyResult,
+    TallyRules, Vote, VotingMode, VotingPowerPolicy, VotingSystemError,
 };
-use crate::types::{Vote, VotingSystemError};
 use crate::{ContractResult, DataKey};
 
+pub(crate) use crate::storage::key_data::get_role_key;
 pub use crate::storage::key_data::{
-    LayerKeyData, NeuronKeyData, NeuronResultKeyData, SubmissionVotesKeyData, SubmissionsKeyData,
-    TallyResultsKeyData, VotingPowersKeyData,
+    BudgetsKeyData, DelegateesKeyData, EligibleVoterKeyData, LayerKeyData,
+    NeuralGovernanceRoundKeyData, NeuronClaimKeyData, NeuronClaimantKeyData, NeuronClaimsKeyData,
+    NeuronKeyData, NeuronResultChunkKeyData, NeuronResultKeyData, RoleKeyData, RoundPhaseKeyData,
+    SubmissionVoterKeyData, SubmissionVotersKeyData, SubmissionVotesChunkKeyData,
+    SubmissionVotesKeyData, SubmissionsKeyData, TallyResultsKeyData, TallyRulesKeyData,
+    VoteCommitmentKeyData, VoteNonceKeyData, VoterRegistryKeyData, VoterVoteKeyData,
+    VotingModeKeyData, VotingPowersKeyData,
 };
 
 mod key_data;
 
+const ONE_DAY_LEDGERS: u32 = 17280;
+
+// Round data is kept for audits long after the round is finalized. Entries are extended whenever they
+// are written or used in calculations, and all data of a round can be extended with `extend_round`.
+const LEDGER_BUMP: u32 = 120 * ONE_DAY_LEDGERS;
+const LEDGER_THRESHOLD: u32 = LEDGER_BUMP - 20 * ONE_DAY_LEDGERS;
+
+/// Bump the instance lifetime by the defined amount
+pub(crate) fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+fn extend_persistent_if_present(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        extend_persistent(env, key);
+    }
+}
+
+/// Fetch an entry in persistent storage and bump it if it exists and `extend` is set.
+///
+/// Read-only simulations don't extend entries, so that they don't write to the ledger.
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey, extend: bool) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if extend && value.is_some() {
+        extend_persistent(env, key);
+    }
+    value
+}
+
 pub(crate) fn read_layer(env: &Env, layer_id: &String) -> ContractResult<Layer> {
     let key = get_layer_key(layer_id);
     env.storage()
@@ -33,6 +86,24 @@ pub(crate) fn remove_layer(env: &Env, layer_id: &String) {
     env.storage().instance().remove(&key);
 }
 
+pub(crate) fn read_layer_inputs(env: &Env, layer_id: &String) -> Vec<String> {
+    let key = get_layer_inputs_key(layer_id);
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub(crate) fn write_layer_inputs(env: &Env, layer_id: &String, inputs: &Vec<String>) {
+    let key = get_layer_inputs_key(layer_id);
+    env.storage().instance().set(&key, inputs);
+}
+
+pub(crate) fn remove_layer_inputs(env: &Env, layer_id: &String) {
+    let key = get_layer_inputs_key(layer_id);
+    env.storage().instance().remove(&key);
+}
+
 pub(crate) fn read_neuron(
     env: &Env,
     layer_id: &String,
@@ -60,12 +131,10 @@ pub(crate) fn read_neuron_result(
     layer_id: &String,
     neuron_id: &String,
     round: u32,
+    extend: bool,
 ) -> ContractResult<Map<String, I256>> {
     let key = get_neuron_result_key(layer_id, neuron_id, round);
-    env.storage()
-        .temporary()
-        .get(&key)
-        .ok_or(VotingSystemError::NeuronResultNotSet)
+    read_persistent(env, &key, extend).ok_or(VotingSystemError::NeuronResultNotSet)
 }
 
 pub(crate) fn write_neuron_result(
@@ -76,7 +145,202 @@ pub(crate) fn write_neuron_result(
     result: &Map<String, I256>,
 ) {
     let key = get_neuron_result_key(layer_id, neuron_id, round);
-    env.storage().temporary().set(&key, result);
+    env.storage().persistent().set(&key, result);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_neuron_result(env: &Env, layer_id: &String, neuron_id: &String, round: u32) {
+    let key = get_neuron_result_key(layer_id, neuron_id, round);
+    env.storage().persistent().remove(&key);
+}
+
+fn read_neuron_claimants_count(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    root: &BytesN<32>,
+) -> u32 {
+    let key = get_neuron_claimants_count_key(layer_id, neuron_id, round, root);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Values of a neuron result claimed by voters under the Merkle root.
+pub(crate) fn read_neuron_claims(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    root: &BytesN<32>,
+    extend: bool,
+) -> Map<String, I256> {
+    let mut claims = Map::new(env);
+    for index in 0..read_neuron_claimants_count(env, layer_id, neuron_id, round, root) {
+        let key = get_neuron_claimant_key(layer_id, neuron_id, round, root, index);
+        let Some(voter) = read_persistent::<Address>(env, &key, extend) else {
+            continue;
+        };
+        let key = get_neuron_claim_key(layer_id, neuron_id, round, root, &voter);
+        if let Some(value) = read_persistent(env, &key, extend) {
+            claims.set(voter.to_string(), value);
+        }
+    }
+    claims
+}
+
+/// Store a value claimed by the voter under the Merkle root, indexing voters claiming for the first
+/// time.
+pub(crate) fn write_neuron_claim(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    root: &BytesN<32>,
+    voter: &Address,
+    value: &I256,
+) {
+    let key = get_neuron_claim_key(layer_id, neuron_id, round, root, voter);
+    if !env.storage().persistent().has(&key) {
+        let index = read_neuron_claimants_count(env, layer_id, neuron_id, round, root);
+        let claimant_key = get_neuron_claimant_key(layer_id, neuron_id, round, root, index);
+        env.storage().persistent().set(&claimant_key, voter);
+        extend_persistent(env, &claimant_key);
+
+        let count_key = get_neuron_claimants_count_key(layer_id, neuron_id, round, root);
+        env.storage().persistent().set(&count_key, &(index + 1));
+        extend_persistent(env, &count_key);
+    }
+    env.storage().persistent().set(&key, value);
+    extend_persistent(env, &key);
+}
+
+/// Whether a neuron result, a chunked upload of it or its Merkle root is stored for the round.
+fn has_neuron_result(env: &Env, layer_id: &String, neuron_id: &String, round: u32) -> bool {
+    let storage = env.storage().persistent();
+    storage.has(&get_neuron_result_key(layer_id, neuron_id, round))
+        || storage.has(&get_neuron_result_upload_key(layer_id, neuron_id, round))
+        || storage.has(&get_neuron_result_root_key(layer_id, neuron_id, round))
+}
+
+/// Neuron result of a round in every form it can be stored in, taken out of storage to be stored
+/// under another neuron ID.
+pub(crate) struct StoredNeuronResult {
+    result: Option<Map<String, I256>>,
+    upload: Option<(ChunkedUpload, Vec<Map<String, I256>>)>,
+    claims: Option<(BytesN<32>, Vec<(Address, I256)>)>,
+}
+
+/// Read the neuron result of a round stored under `neuron_id` and remove it from there.
+pub(crate) fn take_neuron_result(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+) -> StoredNeuronResult {
+    let result = read_neuron_result(env, layer_id, neuron_id, round, false).ok();
+    remove_neuron_result(env, layer_id, neuron_id, round);
+
+    let upload = read_neuron_result_upload(env, layer_id, neuron_id, round).map(|upload| {
+        let mut chunks = Vec::new(env);
+        for chunk in 0..upload.chunks {
+            chunks.push_back(
+                read_neuron_result_chunk(env, layer_id, neuron_id, round, chunk, false)
+                    .unwrap_or_else(|_| Map::new(env)),
+            );
+        }
+        (upload, chunks)
+    });
+    remove_neuron_result_upload(env, layer_id, neuron_id, round);
+
+    let claims = read_neuron_result_root(env, layer_id, neuron_id, round)
+        .ok()
+        .map(|root| {
+            let mut claims = Vec::new(env);
+            for index in 0..read_neuron_claimants_count(env, layer_id, neuron_id, round, &root) {
+                let key = get_neuron_claimant_key(layer_id, neuron_id, round, &root, index);
+                let Some(voter) = env.storage().persistent().get::<_, Address>(&key) else {
+                    continue;
+                };
+                let key = get_neuron_claim_key(layer_id, neuron_id, round, &root, &voter);
+                if let Some(value) = env.storage().persistent().get(&key) {
+                    claims.push_back((voter, value));
+                }
+            }
+            (root, claims)
+        });
+    remove_neuron_result_root(env, layer_id, neuron_id, round);
+
+    StoredNeuronResult {
+        result,
+        upload,
+        claims,
+    }
+}
+
+/// Store a neuron result taken with `take_neuron_result` under `neuron_id`, unless the round
+/// already has a result for the neuron.
+pub(crate) fn put_neuron_result(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    stored: StoredNeuronResult,
+) {
+    if has_neuron_result(env, layer_id, neuron_id, round) {
+        return;
+    }
+
+    if let Some(result) = stored.result {
+        write_neuron_result(env, layer_id, neuron_id, round, &result);
+    }
+    if let Some((upload, chunks)) = stored.upload {
+        for (chunk, result) in chunks.iter().enumerate() {
+            let chunk = u32::try_from(chunk).unwrap();
+            write_neuron_result_chunk(env, layer_id, neuron_id, round, chunk, &result);
+        }
+        write_neuron_result_upload(env, layer_id, neuron_id, round, &upload);
+    }
+    if let Some((root, claims)) = stored.claims {
+        write_neuron_result_root(env, layer_id, neuron_id, round, &root);
+        for (voter, value) in claims {
+            write_neuron_claim(env, layer_id, neuron_id, round, &root, &voter, &value);
+        }
+    }
+}
+
+pub(crate) fn read_neuron_result_root(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+) -> ContractResult<BytesN<32>> {
+    let key = get_neuron_result_root_key(layer_id, neuron_id, round);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(VotingSystemError::NeuronResultRootNotSet)
+}
+
+pub(crate) fn write_neuron_result_root(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    root: &BytesN<32>,
+) {
+    let key = get_neuron_result_root_key(layer_id, neuron_id, round);
+    env.storage().persistent().set(&key, root);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_neuron_result_root(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+) {
+    let key = get_neuron_result_root_key(layer_id, neuron_id, round);
+    env.storage().persistent().remove(&key);
 }
 
 pub(crate) fn read_submission_votes(
@@ -99,26 +363,65 @@ pub(crate) fn write_submission_votes(
 ) {
     let key = get_submission_votes_key(submission_id, round);
     env.storage().persistent().set(&key, votes);
+    extend_persistent(env, &key);
 }
 
-pub(crate) fn read_submissions(env: &Env, round: u32) -> Vec<(String, String)> {
+pub(crate) fn remove_submission_votes(env: &Env, submission_id: &String, round: u32) {
+    let key = get_submission_votes_key(submission_id, round);
+    env.storage().persistent().remove(&key);
+}
+
+/// Rounds set up before submissions were typed store them as `(id, category)` tuples. These are
+/// converted when read, until the round is migrated with `write_submissions`.
+pub(crate) fn read_submissions(env: &Env, round: u32) -> ContractResult<Vec<Submission>> {
     let key = get_submissions_key(round);
-    env.storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or_else(|| Vec::new(env))
+    let Some(stored) = env.storage().persistent().get::<_, Vec<Val>>(&key) else {
+        return Ok(Vec::new(env));
+    };
+
+    let mut submissions = Vec::new(env);
+    for value in stored.iter() {
+        let submission = match Submission::try_from_val(env, &value) {
+            Ok(submission) => submission,
+            Err(_) => read_legacy_submission(env, &value)?,
+        };
+        submissions.push_back(submission);
+    }
+    Ok(submissions)
+}
+
+fn read_legacy_submission(env: &Env, value: &Val) -> ContractResult<Submission> {
+    let (id, category) = <(String, String)>::try_from_val(env, value)
+        .map_err(|_| VotingSystemError::InvalidSubmissionCategory)?;
+    let category = SubmissionCategory::from_name(env, &category)
+        .ok_or(VotingSystemError::InvalidSubmissionCategory)?;
+    Ok(Submission::new(id, category))
 }
 
-pub(crate) fn write_submissions(env: &Env, round: u32, submissions: &Vec<(String, String)>) {
+pub(crate) fn write_submissions(env: &Env, round: u32, submissions: &Vec<Submission>) {
     let key = get_submissions_key(round);
     env.storage().persistent().set(&key, submissions);
+    extend_persistent(env, &key);
 }
 
 pub(crate) fn read_neural_governance(env: &Env) -> ContractResult<NGQ> {
-    env.storage()
+    let stored: Map<Symbol, Val> = env
+        .storage()
         .instance()
         .get(&DataKey::NeuralGovernance)
-        .ok_or(VotingSystemError::NeuralGovernanceNotSet)
+        .ok_or(VotingSystemError::NeuralGovernanceNotSet)?;
+    // neural governance used to consist only of layers, their outputs had a weight of 1 and were
+    // summed
+    if !stored.contains_key(Symbol::new(env, "combiner")) {
+        let layers = stored
+            .get(Symbol::new(env, "layers"))
+            .and_then(|layers| Vec::<String>::try_from_val(env, &layers).ok())
+            .ok_or(VotingSystemError::UnexpectedValue)?;
+        let mut neural_governance = NGQ::new(env);
+        neural_governance.layers = layers;
+        return Ok(neural_governance);
+    }
+    NGQ::try_from_val(env, &stored.to_val()).map_err(|_| VotingSystemError::UnexpectedValue)
 }
 
 pub(crate) fn write_neural_governance(env: &Env, neural_governance: NGQ) {
@@ -127,34 +430,953 @@ pub(crate) fn write_neural_governance(env: &Env, neural_governance: NGQ) {
         .set(&DataKey::NeuralGovernance, &neural_governance);
 }
 
-pub(crate) fn read_voting_powers(env: &Env, round: u32) -> ContractResult<Map<String, I256>> {
-    let key = get_voting_powers_key(round);
+pub(crate) fn read_neural_governance_round(
+    env: &Env,
+    round: u32,
+) -> ContractResult<NeuralGovernanceRound> {
+    let key = get_neural_governance_round_key(round);
+    read_persistent(env, &key, true).ok_or(VotingSystemError::NeuralGovernanceRoundNotSet)
+}
+
+pub(crate) fn write_neural_governance_round(
+    env: &Env,
+    round: u32,
+    neural_governance_round: &NeuralGovernanceRound,
+) {
+    let key = get_neural_governance_round_key(round);
     env.storage()
         .persistent()
-        .get(&key)
-        .ok_or(VotingSystemError::VotingPowersNotSet)
+        .set(&key, neural_governance_round);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_neural_governance_round(env: &Env, round: u32) {
+    let key = get_neural_governance_round_key(round);
+    env.storage().persistent().remove(&key);
+}
+
+pub(crate) fn read_voting_power_policy(env: &Env) -> VotingPowerPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::VotingPowerPolicy)
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_voting_power_policy(env: &Env, policy: &VotingPowerPolicy) {
+    env.storage()
+        .instance()
+        .set(&DataKey::VotingPowerPolicy, policy);
+}
+
+pub(crate) fn read_voting_powers(
+    env: &Env,
+    round: u32,
+    extend: bool,
+) -> ContractResult<Map<String, I256>> {
+    let key = get_voting_powers_key(round);
+    read_persistent(env, &key, extend).ok_or(VotingSystemError::VotingPowersNotSet)
 }
 
 pub(crate) fn write_voting_powers(env: &Env, round: u32, voting_powers: &Map<String, I256>) {
     let key = get_voting_powers_key(round);
     env.storage().persistent().set(&key, voting_powers);
+    extend_persistent(env, &key);
 }
 
-pub(crate) fn read_tally_results(env: &Env, round: u32) -> ContractResult<Map<String, I256>> {
+pub(crate) fn remove_voting_powers(env: &Env, round: u32) {
+    let key = get_voting_powers_key(round);
+    env.storage().persistent().remove(&key);
+}
+
+pub(crate) fn read_tally_results(
+    env: &Env,
+    round: u32,
+) -> ContractResult<Map<String, TallyResult>> {
     let key = get_tally_results_key(round);
-    env.storage()
-        .persistent()
-        .get(&key)
-        .ok_or(VotingSystemError::TallyResultsNotSet)
+    let stored: Map<String, Val> =
+        read_persistent(env, &key, true).ok_or(VotingSystemError::TallyResultsNotSet)?;
+
+    let mut tally_results = Map::new(env);
+    for (submission_id, value) in stored.iter() {
+        let result = match TallyResult::try_from_val(env, &value) {
+            Ok(result) => result,
+            Err(_) => read_legacy_tally_result(env, &value)?,
+        };
+        tally_results.set(submission_id, result);
+    }
+    Ok(tally_results)
+}
+
+/// Results tallied before tally rules existed are plain scores without a quorum, so only the
+/// sign of the score decides whether they passed.
+fn read_legacy_tally_result(env: &Env, value: &Val) -> ContractResult<TallyResult> {
+    let score =
+        I256::try_from_val(env, value).map_err(|_| VotingSystemError::TallyResultsNotSet)?;
+    let passed = score > I256::from_i32(env, 0);
+    Ok(TallyResult {
+        score,
+        voters: 0,
+        participating_power: I256::from_i32(env, 0),
+        passed,
+    })
+}
+
+/// Remove provisional tally results of a round whose inputs changed.
+pub(crate) fn remove_tally_results(env: &Env, round: u32) {
+    let key = get_tally_results_key(round);
+    env.storage().persistent().remove(&key);
 }
 
 pub(crate) fn write_tally_results(
     env: &Env,
     round: u32,
-    submissions_tally_results: &Map<String, I256>,
+    submissions_tally_results: &Map<String, TallyResult>,
 ) {
     let key = get_tally_results_key(round);
     env.storage()
         .persistent()
         .set(&key, submissions_tally_results);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_tally_rules(env: &Env, round: u32) -> TallyRules {
+    let key = get_tally_rules_key(round);
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+pub(crate) fn write_tally_rules(env: &Env, round: u32, rules: &TallyRules) {
+    let key = get_tally_rules_key(round);
+    env.storage().persistent().set(&key, rules);
+    extend_persistent(env, &key);
+}
+
+/// Number of eligible voters of the round, `None` if the round has no voter registry.
+pub(crate) fn read_voter_registry(env: &Env, round: u32) -> Option<u32> {
+    let key = get_voter_registry_key(round);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn write_voter_registry(env: &Env, round: u32, voters_count: u32) {
+    let key = get_voter_registry_key(round);
+    env.storage().persistent().set(&key, &voters_count);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn is_eligible_voter(env: &Env, round: u32, voter_id: &String) -> bool {
+    let key = get_eligible_voter_key(round, voter_id);
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn write_eligible_voter(env: &Env, round: u32, voter_id: &String) {
+    let key = get_eligible_voter_key(round, voter_id);
+    env.storage().persistent().set(&key, &true);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_eligible_voter(env: &Env, round: u32, voter_id: &String) {
+    let key = get_eligible_voter_key(round, voter_id);
+    env.storage().persistent().remove(&key);
+}
+
+pub(crate) fn read_round_phase(env: &Env, round: u32) -> RoundPhase {
+    let key = get_round_phase_key(round);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(RoundPhase::Setup)
+}
+
+pub(crate) fn write_round_phase(env: &Env, round: u32, phase: RoundPhase) {
+    let key = get_round_phase_key(round);
+    env.storage().persistent().set(&key, &phase);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_voting_mode(env: &Env, round: u32) -> VotingMode {
+    let key = get_voting_mode_key(round);
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+pub(crate) fn write_voting_mode(env: &Env, round: u32, mode: VotingMode) {
+    let key = get_voting_mode_key(round);
+    env.storage().persistent().set(&key, &mode);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_vote_commitment(env: &Env, round: u32, voter: &Address) -> Option<BytesN<32>> {
+    let key = get_vote_commitment_key(round, voter);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn write_vote_commitment(
+    env: &Env,
+    round: u32,
+    voter: &Address,
+    commitment: &BytesN<32>,
+) {
+    let key = get_vote_commitment_key(round, voter);
+    env.storage().persistent().set(&key, commitment);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_vote_commitment(env: &Env, round: u32, voter: &Address) {
+    let key = get_vote_commitment_key(round, voter);
+    env.storage().persistent().remove(&key);
+}
+
+/// Nonce of the last signed vote of the voter for the submission.
+pub(crate) fn read_vote_nonce(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    voter: &Address,
+) -> Option<u64> {
+    let key = get_vote_nonce_key(submission_id, round, voter);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn write_vote_nonce(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    voter: &Address,
+    nonce: u64,
+) {
+    let key = get_vote_nonce_key(submission_id, round, voter);
+    env.storage().persistent().set(&key, &nonce);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_voter_vote(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    voter: &Address,
+) -> Option<Vote> {
+    let key = get_voter_vote_key(submission_id, round, voter);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn write_voter_vote(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    voter: &Address,
+    vote: &Vote,
+) {
+    let key = get_voter_vote_key(submission_id, round, voter);
+    env.storage().persistent().set(&key, vote);
+    extend_persistent(env, &key);
+}
+
+/// Voters who cast a vote for the submission, in the order of their first vote.
+pub(crate) fn read_submission_voters(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+) -> Vec<Address> {
+    let mut voters = Vec::new(env);
+    for index in 0..read_submission_voters_count(env, submission_id, round) {
+        let key = get_submission_voter_key(submission_id, round, index);
+        if let Some(voter) = env.storage().persistent().get(&key) {
+            voters.push_back(voter);
+        }
+    }
+    voters
+}
+
+fn read_submission_voters_count(env: &Env, submission_id: &String, round: u32) -> u32 {
+    let key = get_submission_voters_count_key(submission_id, round);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Index a voter who cast their first vote for the submission.
+pub(crate) fn add_submission_voter(env: &Env, submission_id: &String, round: u32, voter: &Address) {
+    let index = read_submission_voters_count(env, submission_id, round);
+    let key = get_submission_voter_key(submission_id, round, index);
+    env.storage().persistent().set(&key, voter);
+    extend_persistent(env, &key);
+
+    let key = get_submission_voters_count_key(submission_id, round);
+    env.storage().persistent().set(&key, &(index + 1));
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_delegatees(
+    env: &Env,
+    voter_id: &String,
+    category: &String,
+    round: u32,
+) -> ContractResult<Vec<String>> {
+    let key = get_delegatees_key(voter_id, category, round);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(VotingSystemError::DelegateesNotFound)
+}
+
+pub(crate) fn write_delegatees(
+    env: &Env,
+    voter_id: &String,
+    category: &String,
+    round: u32,
+    delegatees: &Vec<String>,
+) {
+    let key = get_delegatees_key(voter_id, category, round);
+    env.storage().persistent().set(&key, delegatees);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_budgets(env: &Env, round: u32) -> ContractResult<Map<String, CategoryBudget>> {
+    let key = get_budgets_key(round);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(VotingSystemError::BudgetNotSet)
+}
+
+pub(crate) fn write_budgets(env: &Env, round: u32, budgets: &Map<String, CategoryBudget>) {
+    let key = get_budgets_key(round);
+    env.storage().persistent().set(&key, budgets);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_neuron_result_upload(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+) -> Option<ChunkedUpload> {
+    let key = get_neuron_result_upload_key(layer_id, neuron_id, round);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn write_neuron_result_upload(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    upload: &ChunkedUpload,
+) {
+    let key = get_neuron_result_upload_key(layer_id, neuron_id, round);
+    env.storage().persistent().set(&key, upload);
+    extend_persistent(env, &key);
+}
+
+/// Remove a chunked upload of a neuron result together with its chunks.
+pub(crate) fn remove_neuron_result_upload(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+) {
+    let Some(upload) = read_neuron_result_upload(env, layer_id, neuron_id, round) else {
+        return;
+    };
+    for chunk in 0..upload.chunks {
+        let key = get_neuron_result_chunk_key(layer_id, neuron_id, round, chunk);
+        env.storage().persistent().remove(&key);
+    }
+    let key = get_neuron_result_upload_key(layer_id, neuron_id, round);
+    env.storage().persistent().remove(&key);
+}
+
+pub(crate) fn read_neuron_result_chunk(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    chunk: u32,
+    extend: bool,
+) -> ContractResult<Map<String, I256>> {
+    let key = get_neuron_result_chunk_key(layer_id, neuron_id, round, chunk);
+    read_persistent(env, &key, extend).ok_or(VotingSystemError::NeuronResultNotSet)
+}
+
+pub(crate) fn write_neuron_result_chunk(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    chunk: u32,
+    result: &Map<String, I256>,
+) {
+    let key = get_neuron_result_chunk_key(layer_id, neuron_id, round, chunk);
+    env.storage().persistent().set(&key, result);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_submission_votes_upload(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+) -> Option<ChunkedUpload> {
+    let key = get_submission_votes_upload_key(submission_id, round);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn write_submission_votes_upload(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    upload: &ChunkedUpload,
+) {
+    let key = get_submission_votes_upload_key(submission_id, round);
+    env.storage().persistent().set(&key, upload);
+    extend_persistent(env, &key);
+}
+
+/// Remove a chunked upload of submission votes together with its chunks.
+pub(crate) fn remove_submission_votes_upload(env: &Env, submission_id: &String, round: u32) {
+    let Some(upload) = read_submission_votes_upload(env, submission_id, round) else {
+        return;
+    };
+    for chunk in 0..upload.chunks {
+        let key = get_submission_votes_chunk_key(submission_id, round, chunk);
+        env.storage().persistent().remove(&key);
+    }
+    let key = get_submission_votes_upload_key(submission_id, round);
+    env.storage().persistent().remove(&key);
+}
+
+pub(crate) fn read_submission_votes_chunk(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    chunk: u32,
+) -> ContractResult<Map<String, Vote>> {
+    let key = get_submission_votes_chunk_key(submission_id, round, chunk);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(VotingSystemError::VotesForSubmissionNotSet)
+}
+
+pub(crate) fn write_submission_votes_chunk(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    chunk: u32,
+    votes: &Map<String, Vote>,
+) {
+    let key = get_submission_votes_chunk_key(submission_id, round, chunk);
+    env.storage().persistent().set(&key, votes);
+    extend_persistent(env, &key);
+}
+
+/// Extend the TTL of all stored data of the round.
+///
+/// Covers submissions, their votes, voting powers, tallies, budgets, registered voters with a
+/// voting power and neuron results of the current layers.
+pub(crate) fn extend_round(env: &Env, round: u32) -> ContractResult<()> {
+    extend_persistent_if_present(env, &get_submissions_key(round));
+    extend_persistent_if_present(env, &get_round_phase_key(round));
+    extend_persistent_if_present(env, &get_voting_mode_key(round));
+    extend_persistent_if_present(env, &get_voting_powers_key(round));
+    extend_persistent_if_present(env, &get_neural_governance_round_key(round));
+    extend_persistent_if_present(env, &get_tally_results_key(round));
+    extend_persistent_if_present(env, &get_tally_rules_key(round));
+    extend_persistent_if_present(env, &get_budgets_key(round));
+    extend_persistent_if_present(env, &get_voter_registry_key(round));
+    if let Ok(voting_powers) = read_voting_powers(env, round, false) {
+        for voter_id in voting_powers.keys() {
+            extend_persistent_if_present(env, &get_eligible_voter_key(round, &voter_id));
+        }
+    }
+
+    for submission in read_submissions(env, round)? {
+        let submission_id = submission.id;
+        extend_persistent_if_present(env, &get_submission_votes_key(&submission_id, round));
+        let voters_count = read_submission_voters_count(env, &submission_id, round);
+        extend_persistent_if_present(env, &get_submission_voters_count_key(&submission_id, round));
+        for index in 0..voters_count {
+            extend_persistent_if_present(
+                env,
+                &get_submission_voter_key(&submission_id, round, index),
+            );
+        }
+        for voter in read_submission_voters(env, &submission_id, round) {
+            extend_persistent_if_present(env, &get_voter_vote_key(&submission_id, round, &voter));
+            extend_persistent_if_present(env, &get_vote_nonce_key(&submission_id, round, &voter));
+        }
+        if let Some(upload) = read_submission_votes_upload(env, &submission_id, round) {
+            extend_persistent(env, &get_submission_votes_upload_key(&submission_id, round));
+            for chunk in 0..upload.chunks {
+                extend_persistent_if_present(
+                    env,
+                    &get_submission_votes_chunk_key(&submission_id, round, chunk),
+                );
+            }
+        }
+    }
+
+    // neuron results of the round belong to the layers the voting powers were calculated with
+    let layers = match read_neural_governance_round(env, round) {
+        Ok(neural_governance_round) => neural_governance_round.layers,
+        Err(_) => {
+            let Ok(neural_governance) = read_neural_governance(env) else {
+                return Ok(());
+            };
+            let mut layers = Map::new(env);
+            for layer_id in neural_governance.layers {
+                if let Ok(layer) = read_layer(env, &layer_id) {
+                    layers.set(layer_id, layer);
+                }
+            }
+            layers
+        }
+    };
+    for (layer_id, layer) in layers {
+        for neuron_id in layer.neurons.iter() {
+            extend_persistent_if_present(env, &get_neuron_result_key(&layer_id, &neuron_id, round));
+            if let Ok(root) = read_neuron_result_root(env, &layer_id, &neuron_id, round) {
+                extend_persistent(
+                    env,
+                    &get_neuron_result_root_key(&layer_id, &neuron_id, round),
+                );
+                let claimants_count =
+                    read_neuron_claimants_count(env, &layer_id, &neuron_id, round, &root);
+                extend_persistent_if_present(
+                    env,
+                    &get_neuron_claimants_count_key(&layer_id, &neuron_id, round, &root),
+                );
+                for index in 0..claimants_count {
+                    let key = get_neuron_claimant_key(&layer_id, &neuron_id, round, &root, index);
+                    extend_persistent_if_present(env, &key);
+                    if let Some(voter) = env.storage().persistent().get::<_, Address>(&key) {
+                        extend_persistent_if_present(
+                            env,
+                            &get_neuron_claim_key(&layer_id, &neuron_id, round, &root, &voter),
+                        );
+                    }
+                }
+            }
+            if let Some(upload) = read_neuron_result_upload(env, &layer_id, &neuron_id, round) {
+                extend_persistent(
+                    env,
+                    &get_neuron_result_upload_key(&layer_id, &neuron_id, round),
+                );
+                for chunk in 0..upload.chunks {
+                    extend_persistent_if_present(
+                        env,
+                        &get_neuron_result_chunk_key(&layer_id, &neuron_id, round, chunk),
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VotingSystem;
+    use soroban_sdk::testutils::storage::Persistent as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[test]
+    fn extending_round() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let layer_id = String::from_str(&env, "0");
+        let neuron_id = String::from_str(&env, "0");
+        let round = 25;
+
+        env.as_contract(&contract_id, || {
+            let mut neural_governance = NGQ::new(&env);
+            neural_governance.layers.push_back(layer_id.clone());
+            write_neural_governance(&env, neural_governance);
+            write_layer(
+                &env,
+                &layer_id,
+                &Layer::create(
+                    Vec::from_array(&env, [neuron_id.clone()]),
+                    crate::LayerAggregator::Sum,
+                ),
+            );
+            write_neuron_result(&env, &layer_id, &neuron_id, round, &Map::new(&env));
+            write_voting_powers(&env, round, &Map::new(&env));
+
+            let neuron_result_key = get_neuron_result_key(&layer_id, &neuron_id, round);
+            let voting_powers_key = get_voting_powers_key(round);
+            assert_eq!(
+                env.storage().persistent().get_ttl(&neuron_result_key),
+                LEDGER_BUMP
+            );
+
+            // not extended until the TTL drops below the threshold
+            env.ledger()
+                .with_mut(|ledger| ledger.sequence_number += 10 * ONE_DAY_LEDGERS);
+            extend_round(&env, round).unwrap();
+            assert_eq!(
+                env.storage().persistent().get_ttl(&neuron_result_key),
+                LEDGER_BUMP - 10 * ONE_DAY_LEDGERS
+            );
+
+            env.ledger()
+                .with_mut(|ledger| ledger.sequence_number += 50 * ONE_DAY_LEDGERS);
+            extend_round(&env, round).unwrap();
+            assert_eq!(
+                env.storage().persistent().get_ttl(&neuron_result_key),
+                LEDGER_BUMP
+            );
+            assert_eq!(
+                env.storage().persistent().get_ttl(&voting_powers_key),
+                LEDGER_BUMP
+            );
+        });
+    }
+
+    #[test]
+    fn reading_without_extending() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let layer_id = String::from_str(&env, "0");
+        let neuron_id = String::from_str(&env, "0");
+        let round = 25;
+
+        env.as_contract(&contract_id, || {
+            write_neuron_result(&env, &layer_id, &neuron_id, round, &Map::new(&env));
+            write_voting_powers(&env, round, &Map::new(&env));
+            let neuron_result_key = get_neuron_result_key(&layer_id, &neuron_id, round);
+            let voting_powers_key = get_voting_powers_key(round);
+
+            env.ledger()
+                .with_mut(|ledger| ledger.sequence_number += 30 * ONE_DAY_LEDGERS);
+            read_neuron_result(&env, &layer_id, &neuron_id, round, false).unwrap();
+            read_voting_powers(&env, round, false).unwrap();
+            assert_eq!(
+                env.storage().persistent().get_ttl(&neuron_result_key),
+                LEDGER_BUMP - 30 * ONE_DAY_LEDGERS
+            );
+            assert_eq!(
+                env.storage().persistent().get_ttl(&voting_powers_key),
+                LEDGER_BUMP - 30 * ONE_DAY_LEDGERS
+            );
+
+            read_neuron_result(&env, &layer_id, &neuron_id, round, true).unwrap();
+            read_voting_powers(&env, round, true).unwrap();
+            assert_eq!(
+                env.storage().persistent().get_ttl(&neuron_result_key),
+                LEDGER_BUMP
+            );
+            assert_eq!(
+                env.storage().persistent().get_ttl(&voting_powers_key),
+                LEDGER_BUMP
+            );
+        });
+    }
+
+    #[test]
+    fn reading_legacy_submissions() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let sub1 = String::from_str(&env, "sub1");
+        let sub2 = String::from_str(&env, "sub2");
+
+        env.as_contract(&contract_id, || {
+            let legacy = Vec::from_array(
+                &env,
+                [
+                    (sub1.clone(), String::from_str(&env, "Applications")),
+                    (sub2.clone(), String::from_str(&env, "DeveloperTooling")),
+                ],
+            );
+            env.storage()
+                .persistent()
+                .set(&get_submissions_key(25), &legacy);
+
+            let expected = Vec::from_array(
+                &env,
+                [
+                    Submission::new(sub1.clone(), SubmissionCategory::Applications),
+                    Submission::new(sub2.clone(), SubmissionCategory::DeveloperTooling),
+                ],
+            );
+            assert_eq!(read_submissions(&env, 25), Ok(expected.clone()));
+
+            write_submissions(&env, 25, &expected);
+            let stored: Vec<Submission> = env
+                .storage()
+                .persistent()
+                .get(&get_submissions_key(25))
+                .unwrap();
+            assert_eq!(stored, expected);
+
+            let unknown = Vec::from_array(&env, [(sub1.clone(), String::from_str(&env, "Gaming"))]);
+            env.storage()
+                .persistent()
+                .set(&get_submissions_key(26), &unknown);
+            assert_eq!(
+                read_submissions(&env, 26),
+                Err(VotingSystemError::InvalidSubmissionCategory)
+            );
+        });
+    }
+
+    #[test]
+    fn reading_legacy_neural_governance() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let layers = Vec::from_array(
+            &env,
+            [String::from_str(&env, "0"), String::from_str(&env, "1")],
+        );
+
+        env.as_contract(&contract_id, || {
+            let mut legacy = Map::<Symbol, Val>::new(&env);
+            legacy.set(Symbol::new(&env, "layers"), layers.to_val());
+            env.storage()
+                .instance()
+                .set(&DataKey::NeuralGovernance, &legacy);
+
+            let neural_governance = read_neural_governance(&env).unwrap();
+            let mut expected = NGQ::new(&env);
+            expected.layers = layers.clone();
+            assert_eq!(neural_governance, expected);
+
+            write_neural_governance(&env, neural_governance.clone());
+            assert_eq!(read_neural_governance(&env), Ok(neural_governance));
+        });
+    }
+
+    #[test]
+    fn reading_legacy_tally_results() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let sub1 = String::from_str(&env, "sub1");
+        let sub2 = String::from_str(&env, "sub2");
+
+        env.as_contract(&contract_id, || {
+            let mut legacy = Map::new(&env);
+            legacy.set(sub1.clone(), I256::from_i32(&env, 10));
+            legacy.set(sub2.clone(), I256::from_i32(&env, -5));
+            env.storage()
+                .persistent()
+                .set(&get_tally_results_key(25), &legacy);
+
+            let tally_results = read_tally_results(&env, 25).unwrap();
+            assert_eq!(
+                tally_results.get(sub1.clone()),
+                Some(TallyResult {
+                    score: I256::from_i32(&env, 10),
+                    voters: 0,
+                    participating_power: I256::from_i32(&env, 0),
+                    passed: true,
+                })
+            );
+            assert_eq!(
+                tally_results.get(sub2.clone()),
+                Some(TallyResult {
+                    score: I256::from_i32(&env, -5),
+                    voters: 0,
+                    participating_power: I256::from_i32(&env, 0),
+                    passed: false,
+                })
+            );
+
+            write_tally_results(&env, 25, &tally_results);
+            assert_eq!(read_tally_results(&env, 25), Ok(tally_results));
+        });
+    }
+
+    #[test]
+    fn moving_neuron_results() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let layer_id = String::from_str(&env, "0");
+        let from = String::from_str(&env, "0");
+        let to = String::from_str(&env, "neuron");
+        let voter = Address::generate(&env);
+        let root = BytesN::from_array(&env, &[1; 32]);
+        let upload = ChunkedUpload {
+            chunks: 2,
+            sealed: true,
+        };
+        let mut result = Map::new(&env);
+        result.set(String::from_str(&env, "user1"), I256::from_i32(&env, 10));
+
+        env.as_contract(&contract_id, || {
+            write_neuron_result(&env, &layer_id, &from, 25, &result);
+            for chunk in 0..upload.chunks {
+                write_neuron_result_chunk(&env, &layer_id, &from, 25, chunk, &result);
+            }
+            write_neuron_result_upload(&env, &layer_id, &from, 25, &upload);
+            write_neuron_result_root(&env, &layer_id, &from, 25, &root);
+            let value = I256::from_i32(&env, 20);
+            write_neuron_claim(&env, &layer_id, &from, 25, &root, &voter, &value);
+
+            let stored = take_neuron_result(&env, &layer_id, &from, 25);
+            assert!(!has_neuron_result(&env, &layer_id, &from, 25));
+            put_neuron_result(&env, &layer_id, &to, 25, stored);
+
+            assert_eq!(
+                read_neuron_result(&env, &layer_id, &to, 25, false),
+                Ok(result.clone())
+            );
+            assert_eq!(
+                read_neuron_result_upload(&env, &layer_id, &to, 25),
+                Some(upload)
+            );
+            for chunk in 0..upload.chunks {
+                assert_eq!(
+                    read_neuron_result_chunk(&env, &layer_id, &to, 25, chunk, false),
+                    Ok(result.clone())
+                );
+            }
+            assert_eq!(
+                read_neuron_result_root(&env, &layer_id, &to, 25),
+                Ok(root.clone())
+            );
+            assert_eq!(
+                read_neuron_claims(&env, &layer_id, &to, 25, &root, false),
+                Map::from_array(&env, [(voter.to_string(), value)])
+            );
+
+            // results already stored under the neuron ID are kept
+            let other = Map::from_array(&env, [(voter.to_string(), I256::from_i32(&env, 30))]);
+            write_neuron_result(&env, &layer_id, &from, 25, &other);
+            let stored = take_neuron_result(&env, &layer_id, &from, 25);
+            put_neuron_result(&env, &layer_id, &to, 25, stored);
+            assert_eq!(
+                read_neuron_result(&env, &layer_id, &to, 25, false),
+                Ok(result.clone())
+            );
+        });
+    }
+
+    #[test]
+    fn migrating_legacy_neuron_results() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(VotingSystem, ());
+        let client = crate::VotingSystemClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &25);
+
+        let layer_id = String::from_str(&env, "0");
+        let decimals = I256::from_i128(&env, crate::DECIMALS);
+        let user = String::from_str(&env, "user1");
+
+        // neurons used to be identified by their position in the layer, here the neuron at
+        // position "0" is named "1" and the other way around
+        env.as_contract(&contract_id, || {
+            let mut neural_governance = NGQ::new(&env);
+            neural_governance.layers.push_back(layer_id.clone());
+            write_neural_governance(&env, neural_governance);
+            let mut neurons = Vec::new(&env);
+            for (position, name, value) in [("0", "1", 10), ("1", "0", 20), ("2", "c", 30)] {
+                let neuron_id = String::from_str(&env, position);
+                let neuron = Neuron::create(String::from_str(&env, name), decimals.clone());
+                write_neuron(&env, &layer_id, &neuron_id, &neuron);
+                let result = Map::from_array(&env, [(user.clone(), I256::from_i32(&env, value))]);
+                write_neuron_result(&env, &layer_id, &neuron_id, 25, &result);
+                neurons.push_back(neuron_id);
+            }
+            write_layer(
+                &env,
+                &layer_id,
+                &Layer::create(neurons, crate::LayerAggregator::Sum),
+            );
+        });
+
+        client.update_layer(
+            &layer_id,
+            &Vec::from_array(
+                &env,
+                [
+                    (String::from_str(&env, "1"), decimals.clone()),
+                    (String::from_str(&env, "0"), decimals.clone()),
+                    (String::from_str(&env, "c"), decimals),
+                ],
+            ),
+            &crate::LayerAggregator::Sum,
+        );
+
+        env.as_contract(&contract_id, || {
+            for (name, value) in [("1", 10), ("0", 20), ("c", 30)] {
+                let result =
+                    read_neuron_result(&env, &layer_id, &String::from_str(&env, name), 25, false);
+                assert_eq!(
+                    result.unwrap().get(user.clone()),
+                    Some(I256::from_i32(&env, value))
+                );
+            }
+            assert!(!has_neuron_result(
+                &env,
+                &layer_id,
+                &String::from_str(&env, "2"),
+                25
+            ));
+        });
+    }
+
+    #[test]
+    fn removing_uploads_with_chunks() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let layer_id = String::from_str(&env, "0");
+        let neuron_id = String::from_str(&env, "neuron");
+        let submission_id = String::from_str(&env, "sub1");
+        let upload = ChunkedUpload {
+            chunks: 2,
+            sealed: true,
+        };
+
+        env.as_contract(&contract_id, || {
+            for chunk in 0..upload.chunks {
+                write_neuron_result_chunk(&env, &layer_id, &neuron_id, 25, chunk, &Map::new(&env));
+                write_submission_votes_chunk(&env, &submission_id, 25, chunk, &Map::new(&env));
+            }
+            write_neuron_result_upload(&env, &layer_id, &neuron_id, 25, &upload);
+            write_submission_votes_upload(&env, &submission_id, 25, &upload);
+
+            remove_neuron_result_upload(&env, &layer_id, &neuron_id, 25);
+            remove_submission_votes_upload(&env, &submission_id, 25);
+
+            let persistent = env.storage().persistent();
+            assert!(read_neuron_result_upload(&env, &layer_id, &neuron_id, 25).is_none());
+            assert!(read_submission_votes_upload(&env, &submission_id, 25).is_none());
+            for chunk in 0..upload.chunks {
+                assert!(!persistent.has(&get_neuron_result_chunk_key(
+                    &layer_id, &neuron_id, 25, chunk
+                )));
+                assert!(!persistent.has(&get_submission_votes_chunk_key(
+                    &submission_id,
+                    25,
+                    chunk
+                )));
+            }
+        });
+    }
+
+    #[test]
+    fn indexing_submission_voters() {
+        let env = Env::default();
+        let contract_id = env.register(VotingSystem, ());
+
+        let submission_id = String::from_str(&env, "sub1");
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            add_submission_voter(&env, &submission_id, 25, &voter1);
+            add_submission_voter(&env, &submission_id, 25, &voter2);
+
+            assert_eq!(
+                read_submission_voters(&env, &submission_id, 25),
+                Vec::from_array(&env, [voter1.clone(), voter2.clone()])
+            );
+            assert_eq!(read_submission_voters_count(&env, &submission_id, 25), 2);
+            assert!(read_submission_voters(&env, &submission_id, 26).is_empty());
+        });
+    }
 }
@@ -1,5 +1,6 @@
+use crate::admin::Role;
 use crate::DataKey;
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -7,6 +8,12 @@ pub struct LayerKeyData {
     layer_id: String,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleKeyData {
+    role: Role,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct NeuronResultKeyData {
@@ -41,12 +48,147 @@ pub struct VotingPowersKeyData {
     round: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NeuralGovernanceRoundKeyData {
+    round: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct TallyResultsKeyData {
     round: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundPhaseKeyData {
+    round: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VotingModeKeyData {
+    round: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TallyRulesKeyData {
+    round: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoterRegistryKeyData {
+    round: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EligibleVoterKeyData {
+    round: u32,
+    voter_id: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoteCommitmentKeyData {
+    round: u32,
+    voter: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoteNonceKeyData {
+    submission_id: String,
+    round: u32,
+    voter: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoterVoteKeyData {
+    submission_id: String,
+    round: u32,
+    voter: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionVotersKeyData {
+    submission_id: String,
+    round: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DelegateesKeyData {
+    voter_id: String,
+    category: String,
+    round: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BudgetsKeyData {
+    round: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NeuronResultChunkKeyData {
+    layer_id: String,
+    neuron_id: String,
+    round: u32,
+    chunk: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionVotesChunkKeyData {
+    submission_id: String,
+    round: u32,
+    chunk: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NeuronClaimsKeyData {
+    layer_id: String,
+    neuron_id: String,
+    round: u32,
+    root: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NeuronClaimantKeyData {
+    layer_id: String,
+    neuron_id: String,
+    round: u32,
+    root: BytesN<32>,
+    index: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NeuronClaimKeyData {
+    layer_id: String,
+    neuron_id: String,
+    round: u32,
+    root: BytesN<32>,
+    voter: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionVoterKeyData {
+    submission_id: String,
+    round: u32,
+    index: u32,
+}
+
 pub fn get_layer_key(layer_id: &String) -> DataKey {
     let data = LayerKeyData {
         layer_id: layer_id.clone(),
@@ -54,6 +196,13 @@ pub fn get_layer_key(layer_id: &String) -> DataKey {
     DataKey::LayerKey(data)
 }
 
+pub fn get_layer_inputs_key(layer_id: &String) -> DataKey {
+    let data = LayerKeyData {
+        layer_id: layer_id.clone(),
+    };
+    DataKey::LayerInputs(data)
+}
+
 pub fn get_neuron_key(layer_id: &String, neuron_id: &String) -> DataKey {
     let data = NeuronKeyData {
         layer_id: layer_id.clone(),
@@ -71,6 +220,105 @@ pub fn get_neuron_result_key(layer_id: &String, neuron_id: &String, round: u32)
     DataKey::NeuronResultKey(data)
 }
 
+pub fn get_neuron_result_root_key(layer_id: &String, neuron_id: &String, round: u32) -> DataKey {
+    let data = NeuronResultKeyData {
+        layer_id: layer_id.clone(),
+        neuron_id: neuron_id.clone(),
+        round,
+    };
+    DataKey::NeuronResultRoot(data)
+}
+
+pub fn get_neuron_result_upload_key(layer_id: &String, neuron_id: &String, round: u32) -> DataKey {
+    let data = NeuronResultKeyData {
+        layer_id: layer_id.clone(),
+        neuron_id: neuron_id.clone(),
+        round,
+    };
+    DataKey::NeuronResultUpload(data)
+}
+
+pub fn get_neuron_result_chunk_key(
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    chunk: u32,
+) -> DataKey {
+    let data = NeuronResultChunkKeyData {
+        layer_id: layer_id.clone(),
+        neuron_id: neuron_id.clone(),
+        round,
+        chunk,
+    };
+    DataKey::NeuronResultChunk(data)
+}
+
+pub fn get_neuron_claimants_count_key(
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    root: &BytesN<32>,
+) -> DataKey {
+    let data = NeuronClaimsKeyData {
+        layer_id: layer_id.clone(),
+        neuron_id: neuron_id.clone(),
+        round,
+        root: root.clone(),
+    };
+    DataKey::NeuronClaimantsCount(data)
+}
+
+pub fn get_neuron_claimant_key(
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    root: &BytesN<32>,
+    index: u32,
+) -> DataKey {
+    let data = NeuronClaimantKeyData {
+        layer_id: layer_id.clone(),
+        neuron_id: neuron_id.clone(),
+        round,
+        root: root.clone(),
+        index,
+    };
+    DataKey::NeuronClaimant(data)
+}
+
+pub fn get_neuron_claim_key(
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    root: &BytesN<32>,
+    voter: &Address,
+) -> DataKey {
+    let data = NeuronClaimKeyData {
+        layer_id: layer_id.clone(),
+        neuron_id: neuron_id.clone(),
+        round,
+        root: root.clone(),
+        voter: voter.clone(),
+    };
+    DataKey::NeuronClaim(data)
+}
+
+pub fn get_submission_votes_upload_key(submission_id: &String, round: u32) -> DataKey {
+    let data = SubmissionVotesKeyData {
+        submission_id: submission_id.clone(),
+        round,
+    };
+    DataKey::SubmissionVotesUpload(data)
+}
+
+pub fn get_submission_votes_chunk_key(submission_id: &String, round: u32, chunk: u32) -> DataKey {
+    let data = SubmissionVotesChunkKeyData {
+        submission_id: submission_id.clone(),
+        round,
+        chunk,
+    };
+    DataKey::SubmissionVotesChunk(data)
+}
+
 pub fn get_submission_votes_key(submission_id: &String, round: u32) -> DataKey {
     let data = SubmissionVotesKeyData {
         submission_id: submission_id.clone(),
@@ -89,11 +337,101 @@ pub fn get_voting_powers_key(round: u32) -> DataKey {
     DataKey::VotingPowers(data)
 }
 
+pub fn get_neural_governance_round_key(round: u32) -> DataKey {
+    let data = NeuralGovernanceRoundKeyData { round };
+    DataKey::NeuralGovernanceRound(data)
+}
+
 pub fn get_tally_results_key(round: u32) -> DataKey {
     let data = TallyResultsKeyData { round };
     DataKey::TallyResults(data)
 }
 
+pub fn get_round_phase_key(round: u32) -> DataKey {
+    let data = RoundPhaseKeyData { round };
+    DataKey::RoundPhase(data)
+}
+
+pub fn get_voting_mode_key(round: u32) -> DataKey {
+    let data = VotingModeKeyData { round };
+    DataKey::VotingMode(data)
+}
+
+pub fn get_tally_rules_key(round: u32) -> DataKey {
+    let data = TallyRulesKeyData { round };
+    DataKey::TallyRules(data)
+}
+
+pub fn get_voter_registry_key(round: u32) -> DataKey {
+    let data = VoterRegistryKeyData { round };
+    DataKey::VoterRegistry(data)
+}
+
+pub fn get_eligible_voter_key(round: u32, voter_id: &String) -> DataKey {
+    let data = EligibleVoterKeyData {
+        round,
+        voter_id: voter_id.clone(),
+    };
+    DataKey::EligibleVoter(data)
+}
+
+pub fn get_vote_commitment_key(round: u32, voter: &Address) -> DataKey {
+    let data = VoteCommitmentKeyData {
+        round,
+        voter: voter.clone(),
+    };
+    DataKey::VoteCommitment(data)
+}
+
+pub fn get_vote_nonce_key(submission_id: &String, round: u32, voter: &Address) -> DataKey {
+    let data = VoteNonceKeyData {
+        submission_id: submission_id.clone(),
+        round,
+        voter: voter.clone(),
+    };
+    DataKey::VoteNonce(data)
+}
+
+pub fn get_voter_vote_key(submission_id: &String, round: u32, voter: &Address) -> DataKey {
+    let data = VoterVoteKeyData {
+        submission_id: submission_id.clone(),
+        round,
+        voter: voter.clone(),
+    };
+    DataKey::VoterVote(data)
+}
+
+pub fn get_submission_voters_count_key(submission_id: &String, round: u32) -> DataKey {
+    let data = SubmissionVotersKeyData {
+        submission_id: submission_id.clone(),
+        round,
+    };
+    DataKey::SubmissionVotersCount(data)
+}
+
+pub fn get_submission_voter_key(submission_id: &String, round: u32, index: u32) -> DataKey {
+    let data = SubmissionVoterKeyData {
+        submission_id: submission_id.clone(),
+        round,
+        index,
+    };
+    DataKey::SubmissionVoter(data)
+}
+
+pub fn get_delegatees_key(voter_id: &String, category: &String, round: u32) -> DataKey {
+    let data = DelegateesKeyData {
+        voter_id: voter_id.clone(),
+        category: category.clone(),
+        round,
+    };
+    DataKey::Delegatees(data)
+}
+
+pub fn get_budgets_key(round: u32) -> DataKey {
+    let data = BudgetsKeyData { round };
+    DataKey::Budgets(data)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,3 +483,8 @@ mod tests {
         );
     }
 }
+
+pub fn get_role_key(role: Role) -> DataKey {
+    let data = RoleKeyData { role };
+    DataKey::Role(data)
+}
@@ -0,0 +1,468 @@
+use soroban_sdk::{Env, Map, String, Vec, I256};
+
+use crate::fixed_mul_floor::{fixed_mul_floor, fixed_sqrt_floor, mul_div_floor};
+use crate::quorum::normalize_votes;
+use crate::types::{
+    Submission, TallyMode, TallyResult, TallyRules, Vote, VotingSystemError, ABSTAIN_VOTING_POWER,
+};
+use crate::{ContractResult, DECIMALS};
+
+pub(crate) fn validate_tally_rules(env: &Env, rules: &TallyRules) -> ContractResult<()> {
+    let zero = I256::from_i32(env, 0);
+    let is_negative = |value: &Option<I256>| value.as_ref().is_some_and(|value| *value < zero);
+    if is_negative(&rules.min_power) || is_negative(&rules.no_vote_multiplier) {
+        return Err(VotingSystemError::InvalidTallyRules);
+    }
+    Ok(())
+}
+
+/// Resolve `Delegate` votes of a submission using the delegatees of the voters for `category` in
+/// the round.
+pub(crate) fn resolve_votes(
+    env: &Env,
+    round: u32,
+    submission_votes: Map<String, Vote>,
+    category: Option<String>,
+) -> ContractResult<Map<String, Vote>> {
+    if submission_votes.values().contains(Vote::Delegate) {
+        let category = category.ok_or(VotingSystemError::DelegationCalculationFailed)?;
+        normalize_votes(env, round, &category, &submission_votes)
+    } else {
+        Ok(submission_votes)
+    }
+}
+
+/// Add `Yes` votes of resolved submission votes to the number of submissions each voter voted
+/// `Yes` on.
+pub(crate) fn count_yes_votes(
+    yes_votes: &mut Map<String, u32>,
+    submission_votes: &Map<String, Vote>,
+) {
+    for (voter_id, vote) in submission_votes.iter() {
+        if vote == Vote::Yes {
+            let count = yes_votes.get(voter_id.clone()).unwrap_or(0);
+            yes_votes.set(voter_id, count + 1);
+        }
+    }
+}
+
+/// Weight of a single vote in the tally mode of the rules.
+///
+/// `yes_votes` is the number of submissions each voter of the round voted `Yes` on, it's used
+/// only in the `Budgeted` mode.
+fn vote_weight(
+    env: &Env,
+    mode: TallyMode,
+    voter_id: &String,
+    vote: Vote,
+    voting_power: &I256,
+    yes_votes: &Map<String, u32>,
+) -> I256 {
+    match mode {
+        TallyMode::Linear => voting_power.clone(),
+        TallyMode::Quadratic => {
+            fixed_sqrt_floor(env, voting_power, &I256::from_i128(env, DECIMALS))
+        }
+        TallyMode::Budgeted if vote == Vote::Yes => {
+            let submissions_count = yes_votes.get(voter_id.clone()).unwrap_or(1);
+            mul_div_floor(
+                env,
+                voting_power,
+                &I256::from_i32(env, 1),
+                &I256::from_i128(env, i128::from(submissions_count)),
+            )
+        }
+        TallyMode::Budgeted => voting_power.clone(),
+    }
+}
+
+/// Tally votes of a submission.
+///
+/// The score is the weight of `Yes` votes minus the weight of `No` votes multiplied by the `No`
+/// vote multiplier of the rules, where the weight of a vote depends on the tally mode. The
+/// submission passes when its score is positive and its voters reach the quorum of the rules.
+/// The quorum is always measured in voting power.
+///
+/// `category` of the submission is required only to resolve `Delegate` votes.
+pub(crate) fn tally_votes(
+    env: &Env,
+    round: u32,
+    submission_votes: Map<String, Vote>,
+    category: Option<String>,
+    voting_powers: &Map<String, I256>,
+    rules: &TallyRules,
+    yes_votes: &Map<String, u32>,
+) -> ContractResult<TallyResult> {
+    let submission_votes = resolve_votes(env, round, submission_votes, category)?;
+    let zero = I256::from_i32(env, 0);
+    let mut submission_voting_power_plus = zero.clone();
+    let mut submission_voting_power_minus = zero.clone();
+    let mut voters = 0;
+    let mut participating_power = zero.clone();
+
+    for (voter_id, vote) in submission_votes {
+        let voting_power = match vote {
+            Vote::Yes | Vote::No => voting_powers
+                .get(voter_id.clone())
+                .ok_or(VotingSystemError::NGQResultForVoterMissing)?,
+            // abstaining voters don't need a voting power to be counted
+            Vote::Abstain if rules.count_abstain => voting_powers
+                .get(voter_id.clone())
+                .unwrap_or_else(|| I256::from_i32(env, ABSTAIN_VOTING_POWER)),
+            Vote::Abstain | Vote::Delegate => continue,
+        };
+        let weight = vote_weight(env, rules.mode, &voter_id, vote, &voting_power, yes_votes);
+        match vote {
+            Vote::Yes => {
+                submission_voting_power_plus = submission_voting_power_plus.add(&weight);
+            }
+            Vote::No => {
+                submission_voting_power_minus = submission_voting_power_minus.add(&weight);
+            }
+            Vote::Abstain | Vote::Delegate => (),
+        };
+        voters += 1;
+        participating_power = participating_power.add(&voting_power);
+    }
+
+    if let Some(multiplier) = &rules.no_vote_multiplier {
+        submission_voting_power_minus = fixed_mul_floor(
+            env,
+            &submission_voting_power_minus,
+            multiplier,
+            &I256::from_i128(env, DECIMALS),
+        );
+    }
+    let score = submission_voting_power_plus.sub(&submission_voting_power_minus);
+    let quorum_reached = voters >= rules.min_voters
+        && rules
+            .min_power
+            .as_ref()
+            .is_none_or(|min_power| participating_power >= *min_power);
+    let passed = quorum_reached && score > zero;
+
+    Ok(TallyResult {
+        score,
+        voters,
+        participating_power,
+        passed,
+    })
+}
+
+/// Group tallied submissions by category and order them by score, highest first.
+///
+/// Submissions with equal scores keep the order in which they were set. Submissions that were not
+/// tallied are skipped.
+pub(crate) fn rank_results(
+    env: &Env,
+    submissions: &Vec<Submission>,
+    tally_results: &Map<String, TallyResult>,
+) -> Map<String, Vec<(String, I256)>> {
+    let mut ranked: Map<String, Vec<(String, I256)>> = Map::new(env);
+
+    for submission in submissions.iter() {
+        let Some(TallyResult { score, .. }) = tally_results.get(submission.id.clone()) else {
+            continue;
+        };
+        let submission_id = submission.id;
+        let category = submission.category.name(env);
+
+        let mut ranking = ranked
+            .get(category.clone())
+            .unwrap_or_else(|| Vec::new(env));
+        let position = ranking
+            .iter()
+            .position(|(_id, other_score)| other_score < score)
+            .unwrap_or(ranking.len() as usize);
+        ranking.insert(u32::try_from(position).unwrap(), (submission_id, score));
+        ranked.set(category, ranking);
+    }
+
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SubmissionCategory;
+    use soroban_sdk::vec;
+
+    #[test]
+    fn tallying_votes() {
+        let env = Env::default();
+
+        let user1 = String::from_str(&env, "user1");
+        let user2 = String::from_str(&env, "user2");
+        let user3 = String::from_str(&env, "user3");
+
+        let mut voting_powers = Map::new(&env);
+        voting_powers.set(user1.clone(), I256::from_i32(&env, 5));
+        voting_powers.set(user2.clone(), I256::from_i32(&env, 3));
+
+        let mut votes = Map::new(&env);
+        votes.set(user1, Vote::Yes);
+        votes.set(user2, Vote::No);
+        votes.set(user3, Vote::Abstain);
+
+        assert_eq!(
+            tally_votes(
+                &env,
+                25,
+                votes,
+                None,
+                &voting_powers,
+                &TallyRules::default(),
+                &Map::new(&env)
+            ),
+            Ok(TallyResult {
+                score: I256::from_i32(&env, 2),
+                voters: 2,
+                participating_power: I256::from_i32(&env, 8),
+                passed: true,
+            })
+        );
+    }
+
+    #[test]
+    fn tallying_votes_with_rules() {
+        let env = Env::default();
+
+        let user1 = String::from_str(&env, "user1");
+        let user2 = String::from_str(&env, "user2");
+        let user3 = String::from_str(&env, "user3");
+
+        let mut voting_powers = Map::new(&env);
+        voting_powers.set(user1.clone(), I256::from_i32(&env, 5));
+        voting_powers.set(user2.clone(), I256::from_i32(&env, 2));
+        voting_powers.set(user3.clone(), I256::from_i32(&env, 4));
+
+        let mut votes = Map::new(&env);
+        votes.set(user1, Vote::Yes);
+        votes.set(user2, Vote::No);
+        votes.set(user3, Vote::Abstain);
+
+        let tally = |rules: TallyRules| {
+            tally_votes(
+                &env,
+                25,
+                votes.clone(),
+                None,
+                &voting_powers,
+                &rules,
+                &Map::new(&env),
+            )
+        };
+
+        // No votes count twice
+        let result = tally(TallyRules {
+            no_vote_multiplier: Some(I256::from_i128(&env, 2 * DECIMALS)),
+            ..TallyRules::default()
+        })
+        .unwrap();
+        assert_eq!(result.score, I256::from_i32(&env, 1));
+        assert!(result.passed);
+
+        // 2 voters without abstentions
+        let result = tally(TallyRules {
+            min_voters: 3,
+            ..TallyRules::default()
+        })
+        .unwrap();
+        assert_eq!(result.voters, 2);
+        assert!(!result.passed);
+
+        let result = tally(TallyRules {
+            min_voters: 3,
+            min_power: Some(I256::from_i32(&env, 11)),
+            count_abstain: true,
+            ..TallyRules::default()
+        })
+        .unwrap();
+        assert_eq!(result.voters, 3);
+        assert_eq!(result.participating_power, I256::from_i32(&env, 11));
+        assert_eq!(result.score, I256::from_i32(&env, 3));
+        assert!(result.passed);
+
+        let result = tally(TallyRules {
+            min_power: Some(I256::from_i32(&env, 12)),
+            count_abstain: true,
+            ..TallyRules::default()
+        })
+        .unwrap();
+        assert!(!result.passed);
+
+        // more No than Yes
+        let result = tally(TallyRules {
+            no_vote_multiplier: Some(I256::from_i128(&env, 3 * DECIMALS)),
+            ..TallyRules::default()
+        })
+        .unwrap();
+        assert_eq!(result.score, I256::from_i32(&env, -1));
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn tallying_votes_in_quadratic_mode() {
+        let env = Env::default();
+        let decimals = |value: i128| I256::from_i128(&env, value * DECIMALS);
+
+        let user1 = String::from_str(&env, "user1");
+        let user2 = String::from_str(&env, "user2");
+        let user3 = String::from_str(&env, "user3");
+
+        let mut voting_powers = Map::new(&env);
+        voting_powers.set(user1.clone(), decimals(1_000_000));
+        voting_powers.set(user2.clone(), decimals(2));
+        voting_powers.set(user3.clone(), decimals(9));
+
+        let mut votes = Map::new(&env);
+        votes.set(user1, Vote::Yes);
+        votes.set(user2, Vote::Yes);
+        votes.set(user3, Vote::No);
+
+        let rules = TallyRules {
+            mode: TallyMode::Quadratic,
+            ..TallyRules::default()
+        };
+        let result = tally_votes(
+            &env,
+            25,
+            votes,
+            None,
+            &voting_powers,
+            &rules,
+            &Map::new(&env),
+        )
+        .unwrap();
+        // sqrt(1_000_000) + sqrt(2) - sqrt(9)
+        assert_eq!(
+            result.score,
+            I256::from_i128(
+                &env,
+                1000 * DECIMALS + 1_414_213_562_373_095_048 - 3 * DECIMALS
+            )
+        );
+        // the quorum is measured in voting power
+        assert_eq!(result.participating_power, decimals(1_000_011));
+    }
+
+    #[test]
+    fn tallying_votes_in_budgeted_mode() {
+        let env = Env::default();
+
+        let user1 = String::from_str(&env, "user1");
+        let user2 = String::from_str(&env, "user2");
+        let user3 = String::from_str(&env, "user3");
+
+        let mut voting_powers = Map::new(&env);
+        voting_powers.set(user1.clone(), I256::from_i32(&env, 6));
+        voting_powers.set(user2.clone(), I256::from_i32(&env, 1));
+        voting_powers.set(user3.clone(), I256::from_i32(&env, 5));
+
+        let mut votes = Map::new(&env);
+        votes.set(user1.clone(), Vote::Yes);
+        votes.set(user2.clone(), Vote::No);
+        votes.set(user3.clone(), Vote::Yes);
+
+        let mut yes_votes = Map::new(&env);
+        count_yes_votes(&mut yes_votes, &votes);
+        let mut other_votes = Map::new(&env);
+        other_votes.set(user1.clone(), Vote::Yes);
+        other_votes.set(user2, Vote::Yes);
+        other_votes.set(user3.clone(), Vote::Yes);
+        count_yes_votes(&mut yes_votes, &other_votes);
+        other_votes.remove(user3);
+        count_yes_votes(&mut yes_votes, &other_votes);
+        assert_eq!(yes_votes.get(user1), Some(3));
+
+        let rules = TallyRules {
+            mode: TallyMode::Budgeted,
+            ..TallyRules::default()
+        };
+        // 6 / 3 - 1 + floor(5 / 2)
+        assert_eq!(
+            tally_votes(&env, 25, votes, None, &voting_powers, &rules, &yes_votes)
+                .unwrap()
+                .score,
+            I256::from_i32(&env, 3)
+        );
+    }
+
+    #[test]
+    fn tallying_votes_without_voting_power() {
+        let env = Env::default();
+
+        let mut votes = Map::new(&env);
+        votes.set(String::from_str(&env, "user1"), Vote::Yes);
+
+        assert_eq!(
+            tally_votes(
+                &env,
+                25,
+                votes,
+                None,
+                &Map::new(&env),
+                &TallyRules::default(),
+                &Map::new(&env)
+            ),
+            Err(VotingSystemError::NGQResultForVoterMissing)
+        );
+    }
+
+    fn result(env: &Env, score: i32) -> TallyResult {
+        TallyResult {
+            score: I256::from_i32(env, score),
+            voters: 1,
+            participating_power: I256::from_i32(env, score.abs()),
+            passed: score > 0,
+        }
+    }
+
+    #[test]
+    fn ranking_results() {
+        let env = Env::default();
+
+        let applications = String::from_str(&env, "Applications");
+        let tooling = String::from_str(&env, "DeveloperTooling");
+        let sub1 = String::from_str(&env, "sub1");
+        let sub2 = String::from_str(&env, "sub2");
+        let sub3 = String::from_str(&env, "sub3");
+        let sub4 = String::from_str(&env, "sub4");
+        let sub5 = String::from_str(&env, "sub5");
+        let untallied = String::from_str(&env, "untallied");
+
+        let submissions = vec![
+            &env,
+            Submission::new(sub1.clone(), SubmissionCategory::Applications),
+            Submission::new(sub2.clone(), SubmissionCategory::DeveloperTooling),
+            Submission::new(sub3.clone(), SubmissionCategory::Applications),
+            Submission::new(untallied, SubmissionCategory::Applications),
+            Submission::new(sub4.clone(), SubmissionCategory::Applications),
+            Submission::new(sub5.clone(), SubmissionCategory::Applications),
+        ];
+
+        let mut tally_results = Map::new(&env);
+        tally_results.set(sub1.clone(), result(&env, 10));
+        tally_results.set(sub2.clone(), result(&env, -5));
+        tally_results.set(sub3.clone(), result(&env, 30));
+        tally_results.set(sub4.clone(), result(&env, -20));
+        tally_results.set(sub5.clone(), result(&env, 10));
+
+        let ranked = rank_results(&env, &submissions, &tally_results);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(
+            ranked.get(applications).unwrap(),
+            vec![
+                &env,
+                (sub3, I256::from_i32(&env, 30)),
+                (sub1, I256::from_i32(&env, 10)),
+                (sub5, I256::from_i32(&env, 10)),
+                (sub4, I256::from_i32(&env, -20)),
+            ]
+        );
+        assert_eq!(
+            ranked.get(tooling).unwrap(),
+            vec![&env, (sub2, I256::from_i32(&env, -5))]
+        );
+    }
+}
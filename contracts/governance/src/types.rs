@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, String};
+use soroban_sdk::{contracterror, contracttype, BytesN, Env, String, Vec, I256};
 
 pub const ABSTAIN_VOTING_POWER: i32 = 0;
 
@@ -11,6 +11,33 @@ pub enum SubmissionCategory {
     DeveloperTooling,
 }
 
+impl SubmissionCategory {
+    pub const ALL: [SubmissionCategory; 4] = [
+        SubmissionCategory::Applications,
+        SubmissionCategory::FinancialProtocols,
+        SubmissionCategory::InfrastructureAndServices,
+        SubmissionCategory::DeveloperTooling,
+    ];
+
+    /// Name of the category, used as the key of category budgets, delegatees and rankings.
+    pub fn name(&self, env: &Env) -> String {
+        let name = match self {
+            SubmissionCategory::Applications => "Applications",
+            SubmissionCategory::FinancialProtocols => "FinancialProtocols",
+            SubmissionCategory::InfrastructureAndServices => "InfrastructureAndServices",
+            SubmissionCategory::DeveloperTooling => "DeveloperTooling",
+        };
+        String::from_str(env, name)
+    }
+
+    /// Get the category with the given name, if there is one.
+    pub fn from_name(env: &Env, name: &String) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name(env) == *name)
+    }
+}
+
 #[contracttype]
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -30,9 +57,192 @@ impl Submission {
 pub enum Vote {
     Yes,
     No,
+    /// Vote is resolved from votes of the delegatees the voter selected for the submission category
+    Delegate,
     Abstain,
 }
 
+/// A vote signed off-chain by the voter, uploaded with `upload_signed_votes`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedVote {
+    /// ed25519 public key of the voter's Stellar account
+    pub public_key: BytesN<32>,
+    pub vote: Vote,
+    /// Has to be higher than the nonce of the previous signed vote of the voter for the submission
+    pub nonce: u64,
+    /// Signature of `xdr((contract, round, submission_id, vote, nonce))`
+    pub signature: BytesN<64>,
+}
+
+/// Award budget of a submission category and rules for splitting it between submissions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryBudget {
+    /// Total amount to award within the category.
+    pub amount: i128,
+    /// Award only the best `top_n` submissions.
+    pub top_n: Option<u32>,
+    /// Award only submissions with at least this score.
+    pub min_score: Option<I256>,
+    /// Maximal award of a single submission.
+    pub max_award: Option<i128>,
+    /// Split the budget proportionally to the scores instead of equally.
+    pub pro_rata: bool,
+}
+
+/// Normalization of voting powers applied after caps and the floor.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum PowerNormalization {
+    /// Keep voting powers as they are.
+    #[default]
+    Disabled,
+    /// Scale voting powers linearly to the range between 0 and 1.
+    MinMax,
+    /// Scale voting powers so that they add up to 1.
+    SumToOne,
+}
+
+/// Policies applied to the aggregated voting powers. Values are fixed-point numbers with `DECIMALS`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VotingPowerPolicy {
+    /// Maximal voting power of a single voter.
+    pub max_power: Option<I256>,
+    /// Maximal voting power of a single voter as a share of the total voting power before capping,
+    /// `DECIMALS` being 100%.
+    pub max_share: Option<I256>,
+    /// Minimal voting power of every voter with a voting power, not allowed with `MinMax`
+    /// normalization.
+    pub min_power: Option<I256>,
+    pub normalization: PowerNormalization,
+}
+
+/// Value of a single neuron in the voting power of a voter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NeuronBreakdown {
+    pub layer_id: String,
+    pub neuron_id: String,
+    /// Uploaded neuron result of the voter.
+    pub raw_value: I256,
+    /// Neuron result multiplied by the neuron weight.
+    pub weighted_value: I256,
+}
+
+/// Aggregated value of a single layer in the voting power of a voter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LayerBreakdown {
+    pub layer_id: String,
+    pub value: I256,
+}
+
+/// Explanation of how the voting power of a voter was calculated.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingPowerBreakdown {
+    /// Neurons the voter has a result in, in the evaluation order of their layers.
+    pub neurons: Vec<NeuronBreakdown>,
+    /// Layers the voter has a result in, ordered by layer ID.
+    pub layers: Vec<LayerBreakdown>,
+    /// Weighted results of the final layers combined by the layer combiner, before the voting power
+    /// policy is applied.
+    pub combined_power: I256,
+    /// Voting power stored for the round.
+    pub voting_power: I256,
+}
+
+/// State of data uploaded in multiple chunks.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ChunkedUpload {
+    /// Number of uploaded chunks.
+    pub chunks: u32,
+    /// Sealed uploads are complete, they can't be appended to and can be used in calculations.
+    pub sealed: bool,
+}
+
+/// How voters vote in a round.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum VotingMode {
+    /// Votes are cast with `cast_vote` or uploaded, and are visible right away.
+    #[default]
+    Open,
+    /// Voters commit to a hash of their votes while voting is open and reveal them afterwards.
+    /// Only revealed votes are tallied.
+    CommitReveal,
+}
+
+/// How voting power of a voter is turned into the weight of their votes.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TallyMode {
+    /// Every vote weighs the voting power of the voter.
+    #[default]
+    Linear,
+    /// Every vote weighs the square root of the voting power of the voter.
+    Quadratic,
+    /// The voting power of a voter is split equally between the submissions they vote `Yes` on.
+    /// `No` votes weigh the whole voting power.
+    Budgeted,
+}
+
+/// Rules for tallying submissions of a round. Powers and the multiplier are fixed-point numbers
+/// with `DECIMALS`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TallyRules {
+    /// Minimal number of distinct voters of a submission to reach the quorum.
+    pub min_voters: u32,
+    /// Minimal sum of voting powers of the voters of a submission to reach the quorum.
+    pub min_power: Option<I256>,
+    /// Multiplier of the voting power of `No` votes, `DECIMALS` by default.
+    pub no_vote_multiplier: Option<I256>,
+    /// Count `Abstain` votes, including delegated votes resolved to `Abstain`, toward the quorum.
+    pub count_abstain: bool,
+    pub mode: TallyMode,
+}
+
+/// Tally of a single submission.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TallyResult {
+    /// Weight of `Yes` votes minus the weight of `No` votes multiplied by the `No` vote multiplier.
+    pub score: I256,
+    /// Number of distinct voters counted toward the quorum.
+    pub voters: u32,
+    /// Sum of voting powers of the voters counted toward the quorum.
+    pub participating_power: I256,
+    /// Whether the quorum was reached and the score is positive.
+    pub passed: bool,
+}
+
+/// Lifecycle of a voting round. Phases only move forward, except that changing neuron results
+/// moves a round with calculated voting powers back to `VotingClosed`.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RoundPhase {
+    /// Submissions, votes and neuron results can be uploaded.
+    Setup,
+    /// Voters can cast their votes, or commit to them in commit-reveal rounds.
+    Voting,
+    /// Voters reveal the votes they committed to, only in commit-reveal rounds.
+    Reveal,
+    /// Votes can't be cast or revealed anymore, voting powers are not calculated yet.
+    VotingClosed,
+    /// Voting powers are calculated and submissions can be tallied. Correcting a neuron result
+    /// drops the powers and the provisional tally results and moves the round back to
+    /// `VotingClosed`.
+    PowersCalculated,
+    /// Tally results are finalized by the round operator.
+    Tallied,
+    /// The round is read-only.
+    Finalized,
+}
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum VotingSystemError {
@@ -53,4 +263,27 @@ pub enum VotingSystemError {
     SubmissionDoesNotExist = 14,
     VotingPowersNotSet = 15,
     TallyResultsNotSet = 16,
+    VotingClosed = 17,
+    InvalidRoundPhase = 18,
+    RoundFinalized = 19,
+    BudgetNotSet = 20,
+    InvalidBudget = 21,
+    NeuronResultRootNotSet = 22,
+    InvalidProof = 23,
+    UploadSealed = 24,
+    UploadNotSealed = 25,
+    CyclicLayers = 26,
+    LayerInUse = 27,
+    InvalidVotingPowerPolicy = 28,
+    NeuronExists = 29,
+    NeuralGovernanceRoundNotSet = 30,
+    NoPendingAdmin = 31,
+    InvalidSubmissionCategory = 32,
+    InvalidVotingMode = 33,
+    VoteCommitmentNotSet = 34,
+    InvalidVoteCommitment = 35,
+    VoteNonceUsed = 36,
+    InvalidTallyRules = 37,
+    VoterNotEligible = 38,
+    OwnerRoleNotGrantable = 39,
 }
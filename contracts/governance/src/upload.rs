@@ -0,0 +1,193 @@
+use soroban_sdk::{Env, Map, String, I256};
+
+use crate::storage::{
+    read_neuron_claims, read_neuron_result, read_neuron_result_chunk, read_neuron_result_root,
+    read_neuron_result_upload, read_submission_votes, read_submission_votes_chunk,
+    read_submission_votes_upload, remove_neuron_result, remove_submission_votes,
+    write_neuron_result_chunk, write_neuron_result_upload, write_submission_votes_chunk,
+    write_submission_votes_upload,
+};
+use crate::types::{ChunkedUpload, Vote, VotingSystemError};
+use crate::ContractResult;
+
+const NEW_UPLOAD: ChunkedUpload = ChunkedUpload {
+    chunks: 0,
+    sealed: false,
+};
+
+fn seal(
+    upload: Option<ChunkedUpload>,
+    missing: VotingSystemError,
+) -> ContractResult<ChunkedUpload> {
+    let mut upload = upload.ok_or(missing)?;
+    if upload.sealed {
+        return Err(VotingSystemError::UploadSealed);
+    }
+    upload.sealed = true;
+    Ok(upload)
+}
+
+fn next_chunk(upload: Option<ChunkedUpload>) -> ContractResult<ChunkedUpload> {
+    let upload = upload.unwrap_or(NEW_UPLOAD);
+    if upload.sealed {
+        return Err(VotingSystemError::UploadSealed);
+    }
+    Ok(upload)
+}
+
+fn require_sealed(upload: &ChunkedUpload) -> ContractResult<()> {
+    if upload.sealed {
+        Ok(())
+    } else {
+        Err(VotingSystemError::UploadNotSealed)
+    }
+}
+
+/// Store another chunk of a neuron result under its own key.
+///
+/// The first chunk replaces the neuron result set as a whole, if any. Returns the state of the
+/// upload after appending the chunk.
+pub(crate) fn append_neuron_result_chunk(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    chunk: &Map<String, I256>,
+) -> ContractResult<ChunkedUpload> {
+    let mut upload = next_chunk(read_neuron_result_upload(env, layer_id, neuron_id, round))?;
+    if upload.chunks == 0 {
+        remove_neuron_result(env, layer_id, neuron_id, round);
+    }
+    write_neuron_result_chunk(env, layer_id, neuron_id, round, upload.chunks, chunk);
+    upload.chunks += 1;
+    write_neuron_result_upload(env, layer_id, neuron_id, round, &upload);
+    Ok(upload)
+}
+
+pub(crate) fn seal_neuron_result(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+) -> ContractResult<ChunkedUpload> {
+    let upload = seal(
+        read_neuron_result_upload(env, layer_id, neuron_id, round),
+        VotingSystemError::NeuronResultNotSet,
+    )?;
+    write_neuron_result_upload(env, layer_id, neuron_id, round, &upload);
+    Ok(upload)
+}
+
+/// Visit parts of a neuron result one at a time, without merging them first.
+///
+/// A chunked upload is visited chunk by chunk and has to be sealed first. If a voter is present in
+/// multiple parts, the value from the latest part has to be used. Neuron results set as a Merkle
+/// root consist of the values claimed by voters. Read entries are extended only with `extend`.
+pub(crate) fn for_each_neuron_result_part(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+    extend: bool,
+    mut visit: impl FnMut(Map<String, I256>),
+) -> ContractResult<()> {
+    if let Ok(root) = read_neuron_result_root(env, layer_id, neuron_id, round) {
+        // claims used to be stored in the neuron result itself
+        if let Ok(result) = read_neuron_result(env, layer_id, neuron_id, round, extend) {
+            visit(result);
+        }
+        visit(read_neuron_claims(
+            env, layer_id, neuron_id, round, &root, extend,
+        ));
+        return Ok(());
+    }
+
+    let Some(upload) = read_neuron_result_upload(env, layer_id, neuron_id, round) else {
+        visit(read_neuron_result(env, layer_id, neuron_id, round, extend)?);
+        return Ok(());
+    };
+    require_sealed(&upload)?;
+
+    for chunk in 0..upload.chunks {
+        visit(read_neuron_result_chunk(
+            env, layer_id, neuron_id, round, chunk, extend,
+        )?);
+    }
+    Ok(())
+}
+
+/// Read a whole neuron result, merging its parts.
+pub(crate) fn load_neuron_result(
+    env: &Env,
+    layer_id: &String,
+    neuron_id: &String,
+    round: u32,
+) -> ContractResult<Map<String, I256>> {
+    let mut result = Map::new(env);
+    for_each_neuron_result_part(env, layer_id, neuron_id, round, true, |part| {
+        for (voter_id, value) in part {
+            result.set(voter_id, value);
+        }
+    })?;
+    Ok(result)
+}
+
+/// Store another chunk of submission votes under its own key.
+///
+/// The first chunk replaces the votes set as a whole, if any. Returns the state of the upload after
+/// appending the chunk.
+pub(crate) fn append_votes_chunk(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    chunk: &Map<String, Vote>,
+) -> ContractResult<ChunkedUpload> {
+    let mut upload = next_chunk(read_submission_votes_upload(env, submission_id, round))?;
+    if upload.chunks == 0 {
+        remove_submission_votes(env, submission_id, round);
+    }
+    write_submission_votes_chunk(env, submission_id, round, upload.chunks, chunk);
+    upload.chunks += 1;
+    write_submission_votes_upload(env, submission_id, round, &upload);
+    Ok(upload)
+}
+
+pub(crate) fn seal_votes(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+) -> ContractResult<ChunkedUpload> {
+    let upload = seal(
+        read_submission_votes_upload(env, submission_id, round),
+        VotingSystemError::VotesForSubmissionNotSet,
+    )?;
+    write_submission_votes_upload(env, submission_id, round, &upload);
+    Ok(upload)
+}
+
+/// Add uploaded votes for a submission to `votes`, one chunk at a time if they were uploaded in
+/// chunks.
+///
+/// Chunked uploads have to be sealed first. If a voter is present in multiple chunks, the vote
+/// from the latest chunk is used.
+pub(crate) fn load_submission_votes(
+    env: &Env,
+    submission_id: &String,
+    round: u32,
+    votes: &mut Map<String, Vote>,
+) -> ContractResult<()> {
+    let Some(upload) = read_submission_votes_upload(env, submission_id, round) else {
+        for (voter_id, vote) in read_submission_votes(env, submission_id, round)? {
+            votes.set(voter_id, vote);
+        }
+        return Ok(());
+    };
+    require_sealed(&upload)?;
+
+    for chunk in 0..upload.chunks {
+        for (voter_id, vote) in read_submission_votes_chunk(env, submission_id, round, chunk)? {
+            votes.set(voter_id, vote);
+        }
+    }
+    Ok(())
+}
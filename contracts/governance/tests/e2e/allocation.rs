@@ -0,0 +1,142 @@
+use soroban_sdk::{vec, Env, Map, String, I256};
+
+use governance::types::{CategoryBudget, Submission, SubmissionCategory, Vote, VotingSystemError};
+
+use crate::e2e::common::contract_utils::{deploy_contract, setup_layer};
+
+fn budget(amount: i128) -> CategoryBudget {
+    CategoryBudget {
+        amount,
+        top_n: None,
+        min_score: None,
+        max_award: None,
+        pro_rata: false,
+    }
+}
+
+#[test]
+fn allocating_budgets() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let (layer0, neuron0) = setup_layer(&env, &contract_client);
+
+    let applications = String::from_str(&env, "Applications");
+    let tooling = String::from_str(&env, "DeveloperTooling");
+    let app1 = String::from_str(&env, "app1");
+    let app2 = String::from_str(&env, "app2");
+    let app3 = String::from_str(&env, "app3");
+    let tool1 = String::from_str(&env, "tool1");
+    let tool2 = String::from_str(&env, "tool2");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(app1.clone(), SubmissionCategory::Applications),
+        Submission::new(app2.clone(), SubmissionCategory::Applications),
+        Submission::new(app3.clone(), SubmissionCategory::Applications),
+        Submission::new(tool1.clone(), SubmissionCategory::DeveloperTooling),
+        Submission::new(tool2.clone(), SubmissionCategory::DeveloperTooling),
+    ]);
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+    let user3 = String::from_str(&env, "user3");
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(user1.clone(), I256::from_i128(&env, 60));
+    neuron_result.set(user2.clone(), I256::from_i128(&env, 30));
+    neuron_result.set(user3.clone(), I256::from_i128(&env, 10));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+
+    for (submission, voters) in [
+        (&app1, vec![&env, user1.clone(), user2.clone()]),
+        (&app2, vec![&env, user2.clone()]),
+        (&app3, vec![&env, user3.clone()]),
+        (&tool1, vec![&env, user1.clone()]),
+        (&tool2, vec![&env, user3.clone()]),
+    ] {
+        let mut votes = Map::new(&env);
+        for voter in voters {
+            votes.set(voter, Vote::Yes);
+        }
+        contract_client.set_votes_for_submission(submission, &votes);
+    }
+
+    contract_client.calculate_voting_powers();
+    contract_client.tally_round(&0, &u32::MAX);
+
+    // provisional tally results are not used for awards
+    assert_eq!(
+        contract_client.try_get_allocations(&25),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    contract_client.finalize_tally();
+    assert_eq!(
+        contract_client.try_get_allocations(&25),
+        Err(Ok(VotingSystemError::BudgetNotSet))
+    );
+
+    // app1: 90, app2: 30, app3: 10
+    contract_client.set_category_budget(
+        &applications,
+        &CategoryBudget {
+            top_n: Some(2),
+            max_award: Some(600),
+            pro_rata: true,
+            ..budget(1000)
+        },
+    );
+    // tool1: 60, tool2: 10
+    contract_client.set_category_budget(
+        &tooling,
+        &CategoryBudget {
+            min_score: Some(I256::from_i128(&env, 20)),
+            ..budget(500)
+        },
+    );
+    assert_eq!(contract_client.get_category_budgets(&25).len(), 2);
+
+    let allocations = contract_client.get_allocations(&25);
+    assert_eq!(allocations.len(), 3);
+    assert_eq!(allocations.get(app1), Some(600));
+    assert_eq!(allocations.get(app2), Some(250));
+    assert_eq!(allocations.get(app3), None);
+    assert_eq!(allocations.get(tool1), Some(500));
+    assert_eq!(allocations.get(tool2), None);
+
+    contract_client.finalize_round();
+    assert_eq!(
+        contract_client.try_set_category_budget(&tooling, &budget(100)),
+        Err(Ok(VotingSystemError::RoundFinalized))
+    );
+}
+
+#[test]
+fn setting_invalid_budget() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let category = String::from_str(&env, "Applications");
+
+    assert_eq!(
+        contract_client.try_set_category_budget(&category, &budget(-100)),
+        Err(Ok(VotingSystemError::InvalidBudget))
+    );
+    assert_eq!(
+        contract_client.try_set_category_budget(
+            &category,
+            &CategoryBudget {
+                max_award: Some(-1),
+                ..budget(100)
+            }
+        ),
+        Err(Ok(VotingSystemError::InvalidBudget))
+    );
+    assert_eq!(
+        contract_client.try_set_category_budget(&String::from_str(&env, "Gaming"), &budget(100)),
+        Err(Ok(VotingSystemError::InvalidSubmissionCategory))
+    );
+}
@@ -3,7 +3,10 @@ use soroban_sdk::testutils::{
     Address as AddressTrait, AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthInvoke,
 };
 use soroban_sdk::xdr::{ScErrorCode, ScErrorType};
-use soroban_sdk::{vec, Address, Env, Error, IntoVal, Symbol};
+use soroban_sdk::{vec, Address, Env, Error, IntoVal, Map, String, Symbol};
+
+use governance::types::{Submission, SubmissionCategory, Vote, VotingSystemError};
+use governance::Role;
 
 #[test]
 fn uninitialized_contract_is_not_callable() {
@@ -66,6 +69,32 @@ fn transfer_admin() {
         },
     }]);
     contract_client.transfer_admin(&new_admin);
+    assert_eq!(contract_client.get_pending_admin(), Some(new_admin.clone()));
+
+    // The transfer has to be accepted by the new admin
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_client.address,
+            fn_name: "accept_admin",
+            args: vec![&env],
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(contract_client.try_accept_admin().is_err());
+    assert!(contract_client.has_role(&Role::Owner, &admin));
+
+    env.mock_auths(&[MockAuth {
+        address: &new_admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_client.address,
+            fn_name: "accept_admin",
+            args: vec![&env],
+            sub_invokes: &[],
+        },
+    }]);
+    contract_client.accept_admin();
+    assert_eq!(contract_client.get_pending_admin(), None);
 
     // Verify old admin can no longer modify state
     env.mock_auths(&[MockAuth {
@@ -94,6 +123,33 @@ fn transfer_admin() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn cancel_admin_transfer() {
+    let env = Env::default();
+    let contract_client = deploy_contract_without_initialization(&env);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    contract_client.initialize(&admin, &25);
+
+    assert_eq!(
+        contract_client.try_accept_admin(),
+        Err(Ok(VotingSystemError::NoPendingAdmin.into()))
+    );
+
+    let new_admin = Address::generate(&env);
+    contract_client.transfer_admin(&new_admin);
+    contract_client.cancel_admin_transfer();
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(contract_client.get_pending_admin(), None);
+
+    assert_eq!(
+        contract_client.try_accept_admin(),
+        Err(Ok(VotingSystemError::NoPendingAdmin.into()))
+    );
+    assert_eq!(contract_client.get_role_holder(&Role::Owner), admin);
+}
+
 #[test]
 #[should_panic(expected = "Error(WasmVm, InvalidAction)")]
 fn set_admin_again_panics() {
@@ -104,3 +160,156 @@ fn set_admin_again_panics() {
     contract_client.initialize(&admin, &25);
     contract_client.initialize(&admin, &25);
 }
+
+#[test]
+fn cast_vote_requires_voter_auth() {
+    let env = Env::default();
+    let contract_client = deploy_contract_without_initialization(&env);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    contract_client.initialize(&admin, &25);
+
+    let submission = String::from_str(&env, "sub1");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
+    ]);
+    contract_client.open_voting();
+
+    let voter = Address::generate(&env);
+    contract_client.cast_vote(&voter, &submission, &Vote::Yes);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            voter.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_client.address.clone(),
+                    Symbol::new(&env, "cast_vote"),
+                    vec![
+                        &env,
+                        voter.into_val(&env),
+                        submission.into_val(&env),
+                        Vote::Yes.into_val(&env)
+                    ]
+                )),
+                sub_invocations: std::vec![],
+            }
+        ),]
+    );
+
+    // Admin can not cast a vote on behalf of the voter
+    let other_voter = Address::generate(&env);
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_client.address,
+            fn_name: "cast_vote",
+            args: vec![
+                &env,
+                other_voter.into_val(&env),
+                submission.into_val(&env),
+                Vote::Yes.into_val(&env),
+            ],
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(contract_client
+        .try_cast_vote(&other_voter, &submission, &Vote::Yes)
+        .is_err());
+}
+
+#[test]
+fn roles() {
+    let env = Env::default();
+    let contract_client = deploy_contract_without_initialization(&env);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    contract_client.initialize(&admin, &25);
+
+    // roles that were not granted are held by the owner
+    assert_eq!(contract_client.get_role_holder(&Role::Owner), admin);
+    assert_eq!(contract_client.get_role_holder(&Role::DataUploader), admin);
+    assert!(contract_client.has_role(&Role::RoundOperator, &admin));
+
+    let uploader = Address::generate(&env);
+    contract_client.grant_role(&Role::DataUploader, &uploader);
+    assert_eq!(env.auths()[0].0, admin);
+    assert!(contract_client.has_role(&Role::DataUploader, &uploader));
+    assert!(!contract_client.has_role(&Role::DataUploader, &admin));
+    assert!(!contract_client.has_role(&Role::Configurator, &uploader));
+
+    let submission = String::from_str(&env, "sub1");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
+    ]);
+    assert_eq!(env.auths()[0].0, admin);
+
+    let mut votes = Map::new(&env);
+    votes.set(String::from_str(&env, "user1"), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission, &votes);
+    assert_eq!(env.auths()[0].0, uploader);
+
+    // the owner can no longer upload data
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_client.address,
+            fn_name: "set_votes_for_submission",
+            args: vec![&env, submission.into_val(&env), votes.into_val(&env)],
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(contract_client
+        .try_set_votes_for_submission(&submission, &votes)
+        .is_err());
+
+    // and the uploader can't manage rounds or roles
+    env.mock_auths(&[MockAuth {
+        address: &uploader,
+        invoke: &MockAuthInvoke {
+            contract: &contract_client.address,
+            fn_name: "set_current_round",
+            args: vec![&env, 30_u32.into_val(&env)],
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(contract_client.try_set_current_round(&30).is_err());
+    env.mock_auths(&[MockAuth {
+        address: &uploader,
+        invoke: &MockAuthInvoke {
+            contract: &contract_client.address,
+            fn_name: "revoke_role",
+            args: vec![&env, Role::DataUploader.into_val(&env)],
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(contract_client
+        .try_revoke_role(&Role::DataUploader)
+        .is_err());
+
+    env.mock_all_auths();
+    contract_client.revoke_role(&Role::DataUploader);
+    assert_eq!(contract_client.get_role_holder(&Role::DataUploader), admin);
+
+    // eligible voters are registered by the attestor
+    let attestor = Address::generate(&env);
+    contract_client.grant_role(&Role::Attestor, &attestor);
+    let voters = vec![&env, String::from_str(&env, "user1")];
+    contract_client.register_voters(&voters);
+    assert_eq!(env.auths()[0].0, attestor);
+
+    // the owner is changed with transfer_admin only
+    assert_eq!(
+        contract_client.try_grant_role(&Role::Owner, &uploader),
+        Err(Ok(VotingSystemError::OwnerRoleNotGrantable.into()))
+    );
+    assert_eq!(
+        contract_client.try_revoke_role(&Role::Owner),
+        Err(Ok(VotingSystemError::OwnerRoleNotGrantable.into()))
+    );
+    assert_eq!(contract_client.get_role_holder(&Role::Owner), admin);
+}
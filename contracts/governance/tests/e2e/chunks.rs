@@ -0,0 +1,287 @@
+use soroban_sdk::{Env, Map, String, I256};
+
+use governance::types::{Vote, VotingSystemError};
+
+use crate::e2e::common::contract_utils::{deploy_contract, setup_layer, setup_submission};
+
+fn neuron_result_chunk(env: &Env, from: u32, to: u32) -> Map<String, I256> {
+    let mut chunk = Map::new(env);
+    for i in from..to {
+        chunk.set(
+            String::from_str(env, std::format!("user{i}").as_str()),
+            I256::from_i128(env, i128::from(i)),
+        );
+    }
+    chunk
+}
+
+#[test]
+fn uploading_neuron_result_in_chunks() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    assert_eq!(
+        contract_client.append_neuron_result_chunk(
+            &layer_id,
+            &neuron_id,
+            &neuron_result_chunk(&env, 0, 10)
+        ),
+        1
+    );
+    assert_eq!(
+        contract_client.append_neuron_result_chunk(
+            &layer_id,
+            &neuron_id,
+            &neuron_result_chunk(&env, 10, 20)
+        ),
+        2
+    );
+
+    assert_eq!(
+        contract_client.try_calculate_voting_powers(),
+        Err(Ok(VotingSystemError::UploadNotSealed))
+    );
+
+    contract_client.seal_neuron_result(&layer_id, &neuron_id);
+    assert_eq!(
+        contract_client.try_append_neuron_result_chunk(
+            &layer_id,
+            &neuron_id,
+            &neuron_result_chunk(&env, 20, 30)
+        ),
+        Err(Ok(VotingSystemError::UploadSealed))
+    );
+    assert_eq!(
+        contract_client.try_seal_neuron_result(&layer_id, &neuron_id),
+        Err(Ok(VotingSystemError::UploadSealed))
+    );
+
+    assert_eq!(
+        contract_client.get_neuron_result(&layer_id, &neuron_id),
+        neuron_result_chunk(&env, 0, 20)
+    );
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.get_voting_powers(),
+        neuron_result_chunk(&env, 0, 20)
+    );
+
+    // a sealed upload is replaced by uploading the whole result
+    let result = neuron_result_chunk(&env, 0, 1);
+    contract_client.set_neuron_result(&layer_id, &neuron_id, &result);
+    assert_eq!(
+        contract_client.get_neuron_result(&layer_id, &neuron_id),
+        result
+    );
+}
+
+#[test]
+fn sealing_without_chunks() {
+    let env = Env::default();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    assert_eq!(
+        contract_client.try_seal_neuron_result(&layer_id, &neuron_id),
+        Err(Ok(VotingSystemError::NeuronResultNotSet))
+    );
+    assert_eq!(
+        contract_client.try_seal_votes(&String::from_str(&env, "sub1")),
+        Err(Ok(VotingSystemError::VotesForSubmissionNotSet))
+    );
+}
+
+#[test]
+fn uploading_votes_in_chunks() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+    contract_client.set_neuron_result(&layer_id, &neuron_id, &neuron_result_chunk(&env, 0, 4));
+
+    let submission_id = setup_submission(&env, &contract_client);
+
+    let mut chunk1 = Map::new(&env);
+    chunk1.set(String::from_str(&env, "user1"), Vote::Yes);
+    chunk1.set(String::from_str(&env, "user2"), Vote::No);
+    let mut chunk2 = Map::new(&env);
+    chunk2.set(String::from_str(&env, "user3"), Vote::Yes);
+    // the latest chunk takes precedence
+    chunk2.set(String::from_str(&env, "user2"), Vote::Yes);
+
+    assert_eq!(
+        contract_client.try_append_votes_chunk(&String::from_str(&env, "unknown"), &chunk1),
+        Err(Ok(VotingSystemError::SubmissionDoesNotExist))
+    );
+    contract_client.append_votes_chunk(&submission_id, &chunk1);
+    contract_client.append_votes_chunk(&submission_id, &chunk2);
+    assert_eq!(
+        contract_client.try_get_votes_for_submission(&submission_id),
+        Err(Ok(VotingSystemError::UploadNotSealed))
+    );
+
+    contract_client.seal_votes(&submission_id);
+    let votes = contract_client.get_votes_for_submission(&submission_id);
+    assert_eq!(votes.len(), 3);
+    assert_eq!(votes.get(String::from_str(&env, "user2")), Some(Vote::Yes));
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.tally_submission(&submission_id).score,
+        I256::from_i128(&env, 6)
+    );
+}
+
+#[test]
+fn resetting_uploads() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    assert_eq!(
+        contract_client.try_reset_neuron_result_upload(&layer_id, &neuron_id),
+        Err(Ok(VotingSystemError::NeuronResultNotSet))
+    );
+
+    // a sealed upload starts over from the first chunk
+    contract_client.append_neuron_result_chunk(
+        &layer_id,
+        &neuron_id,
+        &neuron_result_chunk(&env, 0, 10),
+    );
+    contract_client.append_neuron_result_chunk(
+        &layer_id,
+        &neuron_id,
+        &neuron_result_chunk(&env, 10, 20),
+    );
+    contract_client.seal_neuron_result(&layer_id, &neuron_id);
+    contract_client.reset_neuron_result_upload(&layer_id, &neuron_id);
+    assert_eq!(
+        contract_client.append_neuron_result_chunk(
+            &layer_id,
+            &neuron_id,
+            &neuron_result_chunk(&env, 20, 25)
+        ),
+        1
+    );
+    contract_client.seal_neuron_result(&layer_id, &neuron_id);
+    assert_eq!(
+        contract_client.get_neuron_result(&layer_id, &neuron_id),
+        neuron_result_chunk(&env, 20, 25)
+    );
+
+    let submission_id = setup_submission(&env, &contract_client);
+    assert_eq!(
+        contract_client.try_reset_votes_upload(&submission_id),
+        Err(Ok(VotingSystemError::VotesForSubmissionNotSet))
+    );
+
+    let mut chunk1 = Map::new(&env);
+    chunk1.set(String::from_str(&env, "user20"), Vote::Yes);
+    contract_client.append_votes_chunk(&submission_id, &chunk1);
+    contract_client.seal_votes(&submission_id);
+    contract_client.reset_votes_upload(&submission_id);
+    assert_eq!(
+        contract_client.try_get_votes_for_submission(&submission_id),
+        Err(Ok(VotingSystemError::VotesForSubmissionNotSet))
+    );
+
+    let mut chunk2 = Map::new(&env);
+    chunk2.set(String::from_str(&env, "user21"), Vote::No);
+    assert_eq!(
+        contract_client.append_votes_chunk(&submission_id, &chunk2),
+        1
+    );
+    contract_client.seal_votes(&submission_id);
+    assert_eq!(
+        contract_client.get_votes_for_submission(&submission_id),
+        chunk2
+    );
+}
+
+#[test]
+fn calculating_voting_powers_from_overlapping_chunks() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    contract_client.append_neuron_result_chunk(
+        &layer_id,
+        &neuron_id,
+        &neuron_result_chunk(&env, 0, 10),
+    );
+    // the latest chunk takes precedence
+    let mut chunk = neuron_result_chunk(&env, 10, 12);
+    chunk.set(String::from_str(&env, "user1"), I256::from_i128(&env, 100));
+    contract_client.append_neuron_result_chunk(&layer_id, &neuron_id, &chunk);
+    contract_client.seal_neuron_result(&layer_id, &neuron_id);
+
+    contract_client.calculate_voting_powers();
+    let mut expected = neuron_result_chunk(&env, 0, 12);
+    expected.set(String::from_str(&env, "user1"), I256::from_i128(&env, 100));
+    assert_eq!(contract_client.get_voting_powers(), expected);
+
+    let user1 = String::from_str(&env, "user1");
+    let breakdown = contract_client.get_voting_power_breakdown(&25, &user1);
+    assert_eq!(
+        breakdown.neurons.get(0).unwrap().raw_value,
+        I256::from_i128(&env, 100)
+    );
+}
+
+#[test]
+fn chunked_upload_replaces_whole_upload() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    contract_client.set_neuron_result(&layer_id, &neuron_id, &neuron_result_chunk(&env, 0, 10));
+    contract_client.append_neuron_result_chunk(
+        &layer_id,
+        &neuron_id,
+        &neuron_result_chunk(&env, 10, 12),
+    );
+    contract_client.seal_neuron_result(&layer_id, &neuron_id);
+    assert_eq!(
+        contract_client.get_neuron_result(&layer_id, &neuron_id),
+        neuron_result_chunk(&env, 10, 12)
+    );
+
+    // the whole result doesn't come back when the upload is discarded
+    contract_client.reset_neuron_result_upload(&layer_id, &neuron_id);
+    assert_eq!(
+        contract_client.try_get_neuron_result(&layer_id, &neuron_id),
+        Err(Ok(VotingSystemError::NeuronResultNotSet))
+    );
+
+    let submission_id = setup_submission(&env, &contract_client);
+    let mut votes = Map::new(&env);
+    votes.set(String::from_str(&env, "user0"), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission_id, &votes);
+
+    let mut chunk = Map::new(&env);
+    chunk.set(String::from_str(&env, "user1"), Vote::No);
+    contract_client.append_votes_chunk(&submission_id, &chunk);
+    contract_client.seal_votes(&submission_id);
+    assert_eq!(
+        contract_client.get_votes_for_submission(&submission_id),
+        chunk
+    );
+    contract_client.reset_votes_upload(&submission_id);
+    assert_eq!(
+        contract_client.try_get_votes_for_submission(&submission_id),
+        Err(Ok(VotingSystemError::VotesForSubmissionNotSet))
+    );
+}
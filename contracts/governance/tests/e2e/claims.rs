@@ -0,0 +1,231 @@
+use soroban_sdk::testutils::Address as AddressTrait;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, Map, Vec, I256};
+
+use governance::types::VotingSystemError;
+
+use crate::e2e::common::contract_utils::{deploy_contract, setup_layer};
+
+fn leaf(env: &Env, voter: &Address, value: i128) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &[0]);
+    data.append(&(voter.to_string(), I256::from_i128(env, value)).to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+fn node(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a < b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &[1]);
+    data.append(&left.clone().into());
+    data.append(&right.clone().into());
+    env.crypto().sha256(&data).into()
+}
+
+#[test]
+fn claiming_voting_power() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let voter3 = Address::generate(&env);
+    let leaf1 = leaf(&env, &voter1, 10);
+    let leaf2 = leaf(&env, &voter2, 20);
+    let leaf3 = leaf(&env, &voter3, 30);
+    let node12 = node(&env, &leaf1, &leaf2);
+    let root = node(&env, &node12, &leaf3);
+
+    contract_client.set_neuron_result_root(&layer_id, &neuron_id, &root);
+    assert_eq!(
+        contract_client.get_neuron_result_root(&layer_id, &neuron_id, &25),
+        root
+    );
+    assert!(contract_client
+        .get_neuron_result(&layer_id, &neuron_id)
+        .is_empty());
+
+    contract_client.claim_voting_power(
+        &voter1,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 10),
+        &vec![&env, leaf2.clone(), leaf3.clone()],
+    );
+    contract_client.claim_voting_power(
+        &voter3,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 30),
+        &vec![&env, node12],
+    );
+
+    contract_client.calculate_voting_powers();
+    let voting_powers = contract_client.get_voting_powers();
+    assert_eq!(voting_powers.len(), 2);
+    assert_eq!(
+        voting_powers.get(voter1.to_string()),
+        Some(I256::from_i128(&env, 10))
+    );
+    assert_eq!(
+        voting_powers.get(voter3.to_string()),
+        Some(I256::from_i128(&env, 30))
+    );
+
+    // values can't be claimed once the voting powers are calculated
+    assert_eq!(
+        contract_client.try_claim_voting_power(
+            &voter2,
+            &layer_id,
+            &neuron_id,
+            &I256::from_i128(&env, 20),
+            &vec![&env, leaf1, leaf3],
+        ),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+}
+
+#[test]
+fn claiming_voting_power_with_invalid_proof() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let leaf1 = leaf(&env, &voter1, 10);
+    let leaf2 = leaf(&env, &voter2, 20);
+
+    let result = contract_client.try_claim_voting_power(
+        &voter1,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 10),
+        &vec![&env, leaf2.clone()],
+    );
+    assert_eq!(result, Err(Ok(VotingSystemError::NeuronResultRootNotSet)));
+
+    contract_client.set_neuron_result_root(&layer_id, &neuron_id, &node(&env, &leaf1, &leaf2));
+
+    // claimed value doesn't match the committed one
+    let result = contract_client.try_claim_voting_power(
+        &voter1,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 1000),
+        &vec![&env, leaf2.clone()],
+    );
+    assert_eq!(result, Err(Ok(VotingSystemError::InvalidProof)));
+
+    // proof of another voter
+    let result = contract_client.try_claim_voting_power(
+        &voter2,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 10),
+        &vec![&env, leaf2],
+    );
+    assert_eq!(result, Err(Ok(VotingSystemError::InvalidProof)));
+
+    let result = contract_client.try_claim_voting_power(
+        &voter1,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 10),
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(VotingSystemError::InvalidProof)));
+}
+
+#[test]
+fn uploading_neuron_result_replaces_root() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    let voter = Address::generate(&env);
+    let leaf = leaf(&env, &voter, 10);
+    contract_client.set_neuron_result_root(&layer_id, &neuron_id, &leaf);
+    contract_client.claim_voting_power(
+        &voter,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 10),
+        &Vec::new(&env),
+    );
+
+    contract_client.set_neuron_result(&layer_id, &neuron_id, &Map::new(&env));
+    assert_eq!(
+        contract_client.try_get_neuron_result_root(&layer_id, &neuron_id, &25),
+        Err(Ok(VotingSystemError::NeuronResultRootNotSet))
+    );
+    assert!(contract_client
+        .get_neuron_result(&layer_id, &neuron_id)
+        .is_empty());
+}
+
+#[test]
+fn replacing_root_drops_claims() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let (layer_id, neuron_id) = setup_layer(&env, &contract_client);
+
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(voter2.to_string(), I256::from_i128(&env, 50));
+    contract_client.set_neuron_result(&layer_id, &neuron_id, &neuron_result);
+
+    // the uploaded result is replaced by the root
+    let leaf1 = leaf(&env, &voter1, 10);
+    contract_client.set_neuron_result_root(&layer_id, &neuron_id, &leaf1);
+    assert!(contract_client
+        .get_neuron_result(&layer_id, &neuron_id)
+        .is_empty());
+
+    // claiming again doesn't count the voter twice
+    for _ in 0..2 {
+        contract_client.claim_voting_power(
+            &voter1,
+            &layer_id,
+            &neuron_id,
+            &I256::from_i128(&env, 10),
+            &Vec::new(&env),
+        );
+    }
+    let result = contract_client.get_neuron_result(&layer_id, &neuron_id);
+    assert_eq!(result.len(), 1);
+    assert_eq!(
+        result.get(voter1.to_string()),
+        Some(I256::from_i128(&env, 10))
+    );
+
+    // claims under the previous root are not carried over
+    let leaf2 = leaf(&env, &voter2, 20);
+    contract_client.set_neuron_result_root(&layer_id, &neuron_id, &node(&env, &leaf1, &leaf2));
+    assert!(contract_client
+        .get_neuron_result(&layer_id, &neuron_id)
+        .is_empty());
+    contract_client.claim_voting_power(
+        &voter2,
+        &layer_id,
+        &neuron_id,
+        &I256::from_i128(&env, 20),
+        &vec![&env, leaf1],
+    );
+
+    contract_client.calculate_voting_powers();
+    let voting_powers = contract_client.get_voting_powers();
+    assert_eq!(voting_powers.len(), 1);
+    assert_eq!(
+        voting_powers.get(voter2.to_string()),
+        Some(I256::from_i128(&env, 20))
+    );
+}
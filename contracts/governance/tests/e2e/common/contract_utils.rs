@@ -1,6 +1,7 @@
-use governance::{VotingSystem, VotingSystemClient};
+use governance::types::{Submission, SubmissionCategory};
+use governance::{LayerAggregator, VotingSystem, VotingSystemClient, DECIMALS};
 use soroban_sdk::testutils::Address as AddressTrait;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{vec, Address, Env, Map, String, I256};
 
 pub fn deploy_contract_without_initialization(env: &Env) -> VotingSystemClient {
     let contract_id = env.register(VotingSystem, ());
@@ -18,3 +19,42 @@ pub fn deploy_contract(env: &Env) -> VotingSystemClient {
 
     contract_client
 }
+
+/// Add a layer with a single neuron of weight 1.
+///
+/// Returns the IDs of the layer and the neuron.
+pub fn setup_layer(env: &Env, contract_client: &VotingSystemClient) -> (String, String) {
+    let neuron_id = String::from_str(env, "neuron");
+    contract_client.add_layer(
+        &vec![env, (neuron_id.clone(), I256::from_i128(env, DECIMALS))],
+        &LayerAggregator::Sum,
+    );
+    (String::from_str(env, "0"), neuron_id)
+}
+
+/// Set a single `Applications` submission for the active round.
+///
+/// Returns the ID of the submission.
+pub fn setup_submission(env: &Env, contract_client: &VotingSystemClient) -> String {
+    let submission_id = String::from_str(env, "sub1");
+    contract_client.set_submissions(&vec![
+        env,
+        Submission::new(submission_id.clone(), SubmissionCategory::Applications),
+    ]);
+    submission_id
+}
+
+/// Set up the active round with a single neuron layer, its `neuron_result` and a single
+/// submission.
+///
+/// Returns the ID of the submission.
+pub fn setup_round(
+    env: &Env,
+    contract_client: &VotingSystemClient,
+    neuron_result: &Map<String, I256>,
+) -> String {
+    let (layer_id, neuron_id) = setup_layer(env, contract_client);
+    let submission_id = setup_submission(env, contract_client);
+    contract_client.set_neuron_result(&layer_id, &neuron_id, neuron_result);
+    submission_id
+}
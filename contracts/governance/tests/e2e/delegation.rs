@@ -0,0 +1,260 @@
+use soroban_sdk::testutils::Address as AddressTrait;
+use soroban_sdk::{Address, Env, Map, String, Vec, I256};
+
+use governance::types::{Vote, VotingSystemError};
+use governance::VotingSystemClient;
+
+use crate::e2e::common::contract_utils::{deploy_contract, setup_layer, setup_submission};
+
+fn delegatees(env: &Env, count: u32) -> Vec<String> {
+    let mut delegatees = Vec::new(env);
+    for i in 0..count {
+        delegatees.push_back(String::from_str(env, std::format!("del{i}").as_str()));
+    }
+    delegatees
+}
+
+fn set_voting_powers(
+    env: &Env,
+    contract_client: &VotingSystemClient,
+    (layer_id, neuron_id): &(String, String),
+    voters: &Vec<String>,
+) {
+    let mut neuron_result = Map::new(env);
+    for voter in voters.iter() {
+        neuron_result.set(voter, I256::from_i128(env, 10));
+    }
+    contract_client.set_neuron_result(layer_id, neuron_id, &neuron_result);
+    contract_client.calculate_voting_powers();
+}
+
+#[test]
+fn delegated_vote_follows_quorum() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let layer = setup_layer(&env, &contract_client);
+    let submission = setup_submission(&env, &contract_client);
+    let category = String::from_str(&env, "Applications");
+
+    let voter = Address::generate(&env);
+    let delegatees = delegatees(&env, 7);
+    contract_client.set_delegatees(&voter, &category, &delegatees);
+    assert_eq!(
+        contract_client.get_delegatees(&25, &voter, &category),
+        delegatees
+    );
+
+    // 5 out of 6 voting delegatees voted Yes
+    let mut votes = Map::new(&env);
+    for (i, delegatee) in delegatees.iter().enumerate() {
+        match i {
+            0..=4 => votes.set(delegatee, Vote::Yes),
+            5 => votes.set(delegatee, Vote::No),
+            _ => (),
+        }
+    }
+    contract_client.set_votes_for_submission(&submission, &votes);
+
+    contract_client.open_voting();
+    contract_client.cast_vote(&voter, &submission, &Vote::Delegate);
+
+    let mut voters = delegatees.clone();
+    voters.push_back(voter.to_string());
+    set_voting_powers(&env, &contract_client, &layer, &voters);
+
+    // 5 * Yes - 1 * No + delegated Yes
+    assert_eq!(
+        contract_client.tally_submission(&submission).score,
+        I256::from_i128(&env, 50)
+    );
+}
+
+#[test]
+fn delegated_vote_abstains_without_enough_delegatee_votes() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let layer = setup_layer(&env, &contract_client);
+    let submission = setup_submission(&env, &contract_client);
+    let category = String::from_str(&env, "Applications");
+
+    let voter = Address::generate(&env);
+    let delegatees = delegatees(&env, 7);
+    contract_client.set_delegatees(&voter, &category, &delegatees);
+
+    let mut votes = Map::new(&env);
+    for delegatee in delegatees.iter().take(4) {
+        votes.set(delegatee, Vote::No);
+    }
+    votes.set(voter.to_string(), Vote::Delegate);
+    contract_client.set_votes_for_submission(&submission, &votes);
+
+    let mut voters = delegatees.clone();
+    voters.push_back(voter.to_string());
+    set_voting_powers(&env, &contract_client, &layer, &voters);
+
+    assert_eq!(
+        contract_client.tally_submission(&submission).score,
+        I256::from_i128(&env, -40)
+    );
+}
+
+#[test]
+fn delegating_without_delegatees() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let submission = setup_submission(&env, &contract_client);
+    let category = String::from_str(&env, "Applications");
+
+    let voter = Address::generate(&env);
+    assert_eq!(
+        contract_client.try_get_delegatees(&25, &voter, &category),
+        Err(Ok(VotingSystemError::DelegateesNotFound))
+    );
+
+    contract_client.open_voting();
+    assert_eq!(
+        contract_client.try_cast_vote(&voter, &submission, &Vote::Delegate),
+        Err(Ok(VotingSystemError::DelegateesNotFound))
+    );
+
+    // Uploaded delegated votes are validated the same way
+    let mut votes = Map::new(&env);
+    votes.set(voter.to_string(), Vote::Delegate);
+    assert_eq!(
+        contract_client.try_set_votes_for_submission(&submission, &votes),
+        Err(Ok(VotingSystemError::DelegateesNotFound))
+    );
+    assert_eq!(
+        contract_client.try_append_votes_chunk(&submission, &votes),
+        Err(Ok(VotingSystemError::DelegateesNotFound))
+    );
+}
+
+#[test]
+fn setting_invalid_delegatees() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let category = String::from_str(&env, "Applications");
+    let voter = Address::generate(&env);
+
+    assert_eq!(
+        contract_client.try_set_delegatees(&voter, &category, &delegatees(&env, 6)),
+        Err(Ok(VotingSystemError::NotEnoughDelegatees))
+    );
+    assert_eq!(
+        contract_client.try_set_delegatees(&voter, &category, &delegatees(&env, 11)),
+        Err(Ok(VotingSystemError::TooManyDelegatees))
+    );
+
+    let mut with_self = delegatees(&env, 7);
+    with_self.push_back(voter.to_string());
+    assert_eq!(
+        contract_client.try_set_delegatees(&voter, &category, &with_self),
+        Err(Ok(VotingSystemError::UnexpectedValue))
+    );
+
+    assert_eq!(
+        contract_client.try_set_delegatees(
+            &voter,
+            &String::from_str(&env, "Gaming"),
+            &delegatees(&env, 7)
+        ),
+        Err(Ok(VotingSystemError::InvalidSubmissionCategory))
+    );
+}
+
+#[test]
+fn changing_delegatees_after_voting_closes() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let layer = setup_layer(&env, &contract_client);
+    let submission = setup_submission(&env, &contract_client);
+    let category = String::from_str(&env, "Applications");
+
+    let voter = Address::generate(&env);
+    let delegatees = delegatees(&env, 7);
+    contract_client.set_delegatees(&voter, &category, &delegatees);
+
+    contract_client.open_voting();
+    contract_client.cast_vote(&voter, &submission, &Vote::Delegate);
+    contract_client.close_voting();
+
+    let mut other_delegatees = delegatees.clone();
+    other_delegatees.set(0, String::from_str(&env, "other"));
+    assert_eq!(
+        contract_client.try_set_delegatees(&voter, &category, &other_delegatees),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    let mut voters = delegatees.clone();
+    voters.push_back(voter.to_string());
+    set_voting_powers(&env, &contract_client, &layer, &voters);
+    assert_eq!(
+        contract_client.try_set_delegatees(&voter, &category, &other_delegatees),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.get_delegatees(&25, &voter, &category),
+        delegatees
+    );
+}
+
+#[test]
+fn changing_delegatees_in_later_round() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let layer = setup_layer(&env, &contract_client);
+    let submission = setup_submission(&env, &contract_client);
+    let category = String::from_str(&env, "Applications");
+
+    let voter = Address::generate(&env);
+    let delegatees = delegatees(&env, 7);
+    contract_client.set_delegatees(&voter, &category, &delegatees);
+
+    let mut votes = Map::new(&env);
+    for delegatee in delegatees.iter() {
+        votes.set(delegatee, Vote::Yes);
+    }
+    votes.set(voter.to_string(), Vote::Delegate);
+    contract_client.set_votes_for_submission(&submission, &votes);
+    contract_client.open_voting();
+    contract_client.close_voting();
+
+    // the voter selects delegatees that didn't vote in round 25 for the next round
+    contract_client.set_current_round(&26);
+    let mut other_delegatees = Vec::new(&env);
+    for i in 0..7 {
+        other_delegatees.push_back(String::from_str(&env, std::format!("other{i}").as_str()));
+    }
+    contract_client.set_delegatees(&voter, &category, &other_delegatees);
+    assert_eq!(
+        contract_client.get_delegatees(&26, &voter, &category),
+        other_delegatees
+    );
+    assert_eq!(
+        contract_client.get_delegatees(&25, &voter, &category),
+        delegatees
+    );
+
+    // the delegated vote of round 25 still follows the delegatees of round 25
+    contract_client.set_current_round(&25);
+    let mut voters = delegatees.clone();
+    voters.push_back(voter.to_string());
+    set_voting_powers(&env, &contract_client, &layer, &voters);
+    assert_eq!(
+        contract_client.tally_submission(&submission).score,
+        I256::from_i128(&env, 80)
+    );
+}
@@ -0,0 +1,238 @@
+use soroban_sdk::testutils::{Address as AddressTrait, Events};
+use soroban_sdk::{vec, Address, Env, IntoVal, Map, String, Symbol, I256};
+
+use governance::types::{RoundPhase, Submission, SubmissionCategory, Vote};
+use governance::{LayerAggregator, DECIMALS};
+
+use crate::e2e::common::contract_utils::deploy_contract;
+
+#[test]
+fn round_events() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    contract_client.set_current_round(&26);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "round_set"), 26_u32).into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(
+            String::from_str(&env, "sub1"),
+            SubmissionCategory::Applications,
+        ),
+    ]);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "submissions_set"), 26_u32).into_val(&env),
+                1_u32.into_val(&env),
+            ),
+        ]
+    );
+
+    contract_client.open_voting();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "round_phase_changed"), 26_u32).into_val(&env),
+                RoundPhase::Voting.into_val(&env),
+            ),
+        ]
+    );
+
+    let voter = Address::generate(&env);
+    let submission_id = String::from_str(&env, "sub1");
+    contract_client.cast_vote(&voter, &submission_id, &Vote::Yes);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "vote_cast"), 26_u32, voter).into_val(&env),
+                (submission_id, Vote::Yes).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn voting_power_events() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let neurons = vec![
+        &env,
+        (
+            String::from_str(&env, "neuron"),
+            I256::from_i128(&env, DECIMALS),
+        ),
+    ];
+    contract_client.add_layer(&neurons, &LayerAggregator::Sum);
+    let layer_id = String::from_str(&env, "0");
+    let neuron_id = String::from_str(&env, "neuron");
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "layer_added"), layer_id.clone()).into_val(&env),
+                (LayerAggregator::Sum, vec![&env, neuron_id.clone()]).into_val(&env),
+            ),
+        ]
+    );
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(String::from_str(&env, "user1"), I256::from_i128(&env, 10));
+    neuron_result.set(String::from_str(&env, "user2"), I256::from_i128(&env, 20));
+    contract_client.set_neuron_result(&layer_id, &neuron_id, &neuron_result);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (
+                    Symbol::new(&env, "neuron_result_set"),
+                    25_u32,
+                    layer_id,
+                    neuron_id,
+                )
+                    .into_val(&env),
+                2_u32.into_val(&env),
+            ),
+        ]
+    );
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "voting_powers_calculated"), 25_u32).into_val(&env),
+                2_u32.into_val(&env),
+            ),
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "round_phase_changed"), 25_u32).into_val(&env),
+                RoundPhase::PowersCalculated.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn admin_events() {
+    let env = Env::default();
+
+    let contract_client = deploy_contract(&env);
+
+    let new_admin = Address::generate(&env);
+    contract_client.transfer_admin(&new_admin);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (
+                    Symbol::new(&env, "admin_transfer_proposed"),
+                    new_admin.clone()
+                )
+                    .into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+
+    contract_client.cancel_admin_transfer();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (
+                    Symbol::new(&env, "admin_transfer_cancelled"),
+                    new_admin.clone()
+                )
+                    .into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+
+    contract_client.transfer_admin(&new_admin);
+    contract_client.accept_admin();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "admin_transferred"), new_admin).into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn registry_events() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+
+    contract_client.register_voters(&vec![&env, user1.clone(), user2, user1.clone()]);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "voters_registered"), 25_u32).into_val(&env),
+                2_u32.into_val(&env),
+            ),
+        ]
+    );
+
+    contract_client.unregister_voters(&vec![&env, user1, String::from_str(&env, "unknown")]);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_client.address.clone(),
+                (Symbol::new(&env, "voters_unregistered"), 25_u32).into_val(&env),
+                1_u32.into_val(&env),
+            ),
+        ]
+    );
+}
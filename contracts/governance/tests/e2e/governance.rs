@@ -1,7 +1,9 @@
-use soroban_sdk::{vec, Env, String, Vec, I256};
+use soroban_sdk::{vec, Env, Map, String, Vec, I256};
 
-use governance::types::VotingSystemError;
-use governance::LayerAggregator;
+use governance::types::{
+    LayerBreakdown, NeuronBreakdown, PowerNormalization, VotingPowerPolicy, VotingSystemError,
+};
+use governance::{LayerAggregator, LayerCombiner, DECIMALS};
 
 use crate::e2e::common::contract_utils::deploy_contract;
 
@@ -29,20 +31,30 @@ fn add_layer() {
         layer.neurons,
         vec![
             &env,
-            String::from_str(&env, "0"),
-            String::from_str(&env, "1")
+            String::from_str(&env, "aaa"),
+            String::from_str(&env, "b")
         ]
     );
 
     let neuron_0 =
-        contract_client.get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "0"));
+        contract_client.get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "aaa"));
     assert_eq!(neuron_0.name, String::from_str(&env, "aaa"));
     assert_eq!(neuron_0.weight, I256::from_i32(&env, 100));
 
     let neuron_1 =
-        contract_client.get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "1"));
+        contract_client.get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "b"));
     assert_eq!(neuron_1.name, String::from_str(&env, "b"));
     assert_eq!(neuron_1.weight, I256::from_i32(&env, 2000));
+
+    let duplicates = vec![
+        &env,
+        (String::from_str(&env, "c"), I256::from_i32(&env, 1)),
+        (String::from_str(&env, "c"), I256::from_i32(&env, 2)),
+    ];
+    assert_eq!(
+        contract_client.try_add_layer(&duplicates, &LayerAggregator::Sum),
+        Err(Ok(VotingSystemError::NeuronExists))
+    );
 }
 
 #[test]
@@ -73,11 +85,12 @@ fn remove_layer() {
         Err(Ok(VotingSystemError::LayerMissing))
     );
     assert_eq!(
-        contract_client.try_get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "0")),
+        contract_client
+            .try_get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "aaa")),
         Err(Ok(VotingSystemError::NeuronMissing))
     );
     assert_eq!(
-        contract_client.try_get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "1")),
+        contract_client.try_get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "b")),
         Err(Ok(VotingSystemError::NeuronMissing))
     );
 }
@@ -97,27 +110,125 @@ fn update_layer() {
     ];
     contract_client.add_layer(&neurons, &LayerAggregator::Sum);
 
+    let layer_id = String::from_str(&env, "0");
+    let mut result = Map::new(&env);
+    result.set(String::from_str(&env, "user1"), I256::from_i32(&env, 7));
+    contract_client.set_neuron_result(&layer_id, &String::from_str(&env, "b"), &result);
+
+    // reordered, "aaa" removed and "cc" added
     let neurons = vec![
         &env,
         (String::from_str(&env, "cc"), I256::from_i32(&env, 3)),
+        (String::from_str(&env, "b"), I256::from_i32(&env, 5)),
     ];
-    contract_client.update_layer(
-        &String::from_str(&env, "0"),
-        &neurons,
-        &LayerAggregator::Product,
-    );
+    contract_client.update_layer(&layer_id, &neurons, &LayerAggregator::Product);
+    contract_client.update_layer(&layer_id, &neurons, &LayerAggregator::Product);
 
-    let layer = contract_client.get_layer(&String::from_str(&env, "0"));
-    assert_eq!(layer.neurons, vec![&env, String::from_str(&env, "0")]);
+    let governance = contract_client.get_neural_governance();
+    assert_eq!(governance.layers, vec![&env, layer_id.clone()]);
+
+    let layer = contract_client.get_layer(&layer_id);
+    assert_eq!(
+        layer.neurons,
+        vec![
+            &env,
+            String::from_str(&env, "cc"),
+            String::from_str(&env, "b")
+        ]
+    );
     assert_eq!(layer.aggregator, LayerAggregator::Product);
 
-    let neuron =
-        contract_client.get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "0"));
+    let neuron = contract_client.get_neuron(&layer_id, &String::from_str(&env, "cc"));
     assert_eq!(neuron.name, String::from_str(&env, "cc"));
     assert_eq!(neuron.weight, I256::from_i32(&env, 3));
 
+    // the result still belongs to the same neuron
+    let neuron = contract_client.get_neuron(&layer_id, &String::from_str(&env, "b"));
+    assert_eq!(neuron.weight, I256::from_i32(&env, 5));
+    assert_eq!(
+        contract_client.get_neuron_result(&layer_id, &String::from_str(&env, "b")),
+        result
+    );
+
+    assert_eq!(
+        contract_client.try_get_neuron(&layer_id, &String::from_str(&env, "aaa")),
+        Err(Ok(VotingSystemError::NeuronMissing))
+    );
+}
+
+#[test]
+fn adding_and_removing_neurons() {
+    let env = Env::default();
+    let contract_client = deploy_contract(&env);
+
+    let neurons = vec![
+        &env,
+        (String::from_str(&env, "a"), I256::from_i32(&env, 1)),
+        (String::from_str(&env, "b"), I256::from_i32(&env, 2)),
+    ];
+    contract_client.add_layer(&neurons, &LayerAggregator::Sum);
+    let layer_id = String::from_str(&env, "0");
+
+    contract_client.remove_neuron(&layer_id, &String::from_str(&env, "a"));
+    contract_client.add_neuron(
+        &layer_id,
+        &String::from_str(&env, "c"),
+        &I256::from_i32(&env, 3),
+    );
+    assert_eq!(
+        contract_client.get_layer(&layer_id).neurons,
+        vec![
+            &env,
+            String::from_str(&env, "b"),
+            String::from_str(&env, "c")
+        ]
+    );
+    assert_eq!(
+        contract_client
+            .get_neuron(&layer_id, &String::from_str(&env, "b"))
+            .weight,
+        I256::from_i32(&env, 2)
+    );
+    assert_eq!(
+        contract_client.try_get_neuron(&layer_id, &String::from_str(&env, "a")),
+        Err(Ok(VotingSystemError::NeuronMissing))
+    );
+
+    assert_eq!(
+        contract_client.try_add_neuron(
+            &layer_id,
+            &String::from_str(&env, "b"),
+            &I256::from_i32(&env, 1)
+        ),
+        Err(Ok(VotingSystemError::NeuronExists))
+    );
+    assert_eq!(
+        contract_client.try_remove_neuron(&layer_id, &String::from_str(&env, "a")),
+        Err(Ok(VotingSystemError::NeuronMissing))
+    );
+    assert_eq!(
+        contract_client.try_add_neuron(
+            &String::from_str(&env, "5"),
+            &String::from_str(&env, "d"),
+            &I256::from_i32(&env, 1)
+        ),
+        Err(Ok(VotingSystemError::LayerMissing))
+    );
+
+    // results of a removed neuron are rejected at upload time
+    let removed = String::from_str(&env, "a");
+    let mut result = Map::new(&env);
+    result.set(String::from_str(&env, "user1"), I256::from_i32(&env, 1));
+    assert_eq!(
+        contract_client.try_set_neuron_result(&layer_id, &removed, &result),
+        Err(Ok(VotingSystemError::NeuronMissing))
+    );
     assert_eq!(
-        contract_client.try_get_neuron(&String::from_str(&env, "0"), &String::from_str(&env, "1")),
+        contract_client.try_append_neuron_result_chunk(&layer_id, &removed, &result),
+        Err(Ok(VotingSystemError::NeuronMissing))
+    );
+    assert_eq!(
+        contract_client.try_seal_neuron_result(&layer_id, &removed),
         Err(Ok(VotingSystemError::NeuronMissing))
     );
 }
@@ -147,10 +258,505 @@ fn add_layer_after_removing() {
 
     let layer = contract_client.get_layer(&String::from_str(&env, "1"));
     assert_eq!(layer.aggregator, LayerAggregator::Product);
-    assert_eq!(layer.neurons, vec![&env, String::from_str(&env, "0"),]);
+    assert_eq!(layer.neurons, vec![&env, String::from_str(&env, "c"),]);
 
     let neuron_0 =
-        contract_client.get_neuron(&String::from_str(&env, "1"), &String::from_str(&env, "0"));
+        contract_client.get_neuron(&String::from_str(&env, "1"), &String::from_str(&env, "c"));
     assert_eq!(neuron_0.name, String::from_str(&env, "c"));
     assert_eq!(neuron_0.weight, I256::from_i32(&env, 1));
 }
+
+fn set_neuron_value(
+    env: &Env,
+    contract_client: &governance::VotingSystemClient,
+    layer_id: &str,
+    neuron_id: &str,
+    value: i128,
+) {
+    let mut result = Map::new(env);
+    result.set(String::from_str(env, "user1"), I256::from_i128(env, value));
+    contract_client.set_neuron_result(
+        &String::from_str(env, layer_id),
+        &String::from_str(env, neuron_id),
+        &result,
+    );
+}
+
+#[test]
+fn hierarchical_layers() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_client = deploy_contract(&env);
+
+    let one = I256::from_i128(&env, DECIMALS);
+    // (trust + reputation) * history
+    contract_client.add_layer(
+        &vec![
+            &env,
+            (String::from_str(&env, "trust"), one.clone()),
+            (String::from_str(&env, "reputation"), one.clone()),
+        ],
+        &LayerAggregator::Sum,
+    );
+    contract_client.add_layer(
+        &vec![&env, (String::from_str(&env, "history"), one.clone())],
+        &LayerAggregator::Product,
+    );
+    let layer0 = String::from_str(&env, "0");
+    let layer1 = String::from_str(&env, "1");
+    contract_client.set_layer_inputs(&layer1, &vec![&env, layer0.clone()]);
+    assert_eq!(
+        contract_client.get_layer_inputs(&layer1),
+        vec![&env, layer0.clone()]
+    );
+
+    set_neuron_value(&env, &contract_client, "0", "trust", 2 * DECIMALS);
+    set_neuron_value(&env, &contract_client, "0", "reputation", 3 * DECIMALS);
+    set_neuron_value(&env, &contract_client, "1", "history", 4 * DECIMALS);
+
+    assert_eq!(
+        contract_client
+            .get_layer_result(&layer1)
+            .get(String::from_str(&env, "user1")),
+        Some(I256::from_i128(&env, 20 * DECIMALS))
+    );
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client
+            .get_voting_powers()
+            .get(String::from_str(&env, "user1")),
+        Some(I256::from_i128(&env, 20 * DECIMALS))
+    );
+
+    assert_eq!(
+        contract_client.try_remove_layer(&layer0),
+        Err(Ok(VotingSystemError::LayerInUse))
+    );
+}
+
+#[test]
+fn cyclic_layers_are_rejected() {
+    let env = Env::default();
+    let contract_client = deploy_contract(&env);
+
+    let neurons = vec![&env, (String::from_str(&env, "a"), I256::from_i32(&env, 1))];
+    contract_client.add_layer(&neurons, &LayerAggregator::Sum);
+    contract_client.add_layer(&neurons, &LayerAggregator::Sum);
+    let layer0 = String::from_str(&env, "0");
+    let layer1 = String::from_str(&env, "1");
+
+    contract_client.set_layer_inputs(&layer1, &vec![&env, layer0.clone()]);
+    assert_eq!(
+        contract_client.try_set_layer_inputs(&layer0, &vec![&env, layer1.clone()]),
+        Err(Ok(VotingSystemError::CyclicLayers))
+    );
+    assert_eq!(
+        contract_client.try_set_layer_inputs(&layer0, &vec![&env, layer0.clone()]),
+        Err(Ok(VotingSystemError::CyclicLayers))
+    );
+    assert_eq!(
+        contract_client.try_set_layer_inputs(&layer0, &vec![&env, String::from_str(&env, "5")]),
+        Err(Ok(VotingSystemError::LayerMissing))
+    );
+
+    // inputs can be cleared
+    contract_client.set_layer_inputs(&layer1, &Vec::new(&env));
+    contract_client.remove_layer(&layer0);
+}
+
+#[test]
+fn weighting_layers() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_client = deploy_contract(&env);
+
+    let one = I256::from_i128(&env, DECIMALS);
+    let neurons = vec![&env, (String::from_str(&env, "neuron"), one.clone())];
+    contract_client.add_layer(&neurons, &LayerAggregator::Sum);
+    contract_client.add_layer(&neurons, &LayerAggregator::Sum);
+    let layer0 = String::from_str(&env, "0");
+    let layer1 = String::from_str(&env, "1");
+
+    set_neuron_value(&env, &contract_client, "0", "neuron", 2 * DECIMALS);
+    set_neuron_value(&env, &contract_client, "1", "neuron", 4 * DECIMALS);
+
+    contract_client.set_layer_weight(&layer0, &I256::from_i128(&env, 3 * DECIMALS));
+    assert_eq!(
+        contract_client
+            .get_neural_governance()
+            .layer_weights
+            .get(layer0.clone()),
+        Some(I256::from_i128(&env, 3 * DECIMALS))
+    );
+    assert_eq!(
+        contract_client.try_set_layer_weight(&String::from_str(&env, "5"), &one),
+        Err(Ok(VotingSystemError::LayerMissing))
+    );
+    assert_eq!(
+        contract_client.try_set_layer_weight(&layer1, &I256::from_i32(&env, -1)),
+        Err(Ok(VotingSystemError::UnexpectedValue))
+    );
+
+    let user1_power = || {
+        contract_client.calculate_voting_powers();
+        contract_client
+            .get_voting_powers()
+            .get(String::from_str(&env, "user1"))
+            .unwrap()
+    };
+
+    // 3 * 2 + 4
+    assert_eq!(user1_power(), I256::from_i128(&env, 10 * DECIMALS));
+
+    contract_client.set_layer_combiner(&LayerCombiner::Product);
+    assert_eq!(user1_power(), I256::from_i128(&env, 24 * DECIMALS));
+
+    contract_client.set_layer_combiner(&LayerCombiner::WeightedMean);
+    assert_eq!(user1_power(), I256::from_i128(&env, 25 * DECIMALS / 10));
+
+    // weights of removed layers are dropped
+    contract_client.remove_layer(&layer0);
+    assert!(contract_client
+        .get_neural_governance()
+        .layer_weights
+        .is_empty());
+    assert_eq!(user1_power(), I256::from_i128(&env, 4 * DECIMALS));
+}
+
+#[test]
+fn applying_voting_power_policy() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_client = deploy_contract(&env);
+
+    contract_client.add_layer(
+        &vec![
+            &env,
+            (
+                String::from_str(&env, "neuron"),
+                I256::from_i128(&env, DECIMALS),
+            ),
+        ],
+        &LayerAggregator::Sum,
+    );
+    let mut result = Map::new(&env);
+    for (user, value) in [("user1", 1), ("user2", 5), ("user3", 10)] {
+        result.set(
+            String::from_str(&env, user),
+            I256::from_i128(&env, value * DECIMALS),
+        );
+    }
+    contract_client.set_neuron_result(
+        &String::from_str(&env, "0"),
+        &String::from_str(&env, "neuron"),
+        &result,
+    );
+
+    assert_eq!(
+        contract_client.get_voting_power_policy(),
+        VotingPowerPolicy::default()
+    );
+    let invalid = VotingPowerPolicy {
+        max_share: Some(I256::from_i128(&env, 2 * DECIMALS)),
+        ..VotingPowerPolicy::default()
+    };
+    assert_eq!(
+        contract_client.try_set_voting_power_policy(&invalid),
+        Err(Ok(VotingSystemError::InvalidVotingPowerPolicy))
+    );
+
+    let policy = VotingPowerPolicy {
+        max_power: Some(I256::from_i128(&env, 8 * DECIMALS)),
+        max_share: Some(I256::from_i128(&env, DECIMALS / 4)),
+        min_power: Some(I256::from_i128(&env, 2 * DECIMALS)),
+        normalization: PowerNormalization::Disabled,
+    };
+    contract_client.set_voting_power_policy(&policy);
+    assert_eq!(contract_client.get_voting_power_policy(), policy);
+
+    // capped at 25% of the total power of 16 and raised to the floor of 2
+    contract_client.calculate_voting_powers();
+    let voting_powers = contract_client.get_voting_powers();
+    for (user, value) in [("user1", 2), ("user2", 4), ("user3", 4)] {
+        assert_eq!(
+            voting_powers.get(String::from_str(&env, user)),
+            Some(I256::from_i128(&env, value * DECIMALS))
+        );
+    }
+
+    contract_client.set_voting_power_policy(&VotingPowerPolicy {
+        normalization: PowerNormalization::SumToOne,
+        ..policy
+    });
+    contract_client.calculate_voting_powers();
+    let voting_powers = contract_client.get_voting_powers();
+    assert_eq!(
+        voting_powers.get(String::from_str(&env, "user1")),
+        Some(I256::from_i128(&env, DECIMALS / 5))
+    );
+    assert_eq!(
+        voting_powers.get(String::from_str(&env, "user3")),
+        Some(I256::from_i128(&env, 2 * DECIMALS / 5))
+    );
+}
+
+#[test]
+fn explaining_voting_power() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_client = deploy_contract(&env);
+
+    let one = I256::from_i128(&env, DECIMALS);
+    // (trust + 2 * reputation) * history + 2 * (0.5 * bonus)
+    contract_client.add_layer(
+        &vec![
+            &env,
+            (String::from_str(&env, "trust"), one.clone()),
+            (
+                String::from_str(&env, "reputation"),
+                I256::from_i128(&env, 2 * DECIMALS),
+            ),
+        ],
+        &LayerAggregator::Sum,
+    );
+    contract_client.add_layer(
+        &vec![&env, (String::from_str(&env, "history"), one.clone())],
+        &LayerAggregator::Product,
+    );
+    contract_client.add_layer(
+        &vec![
+            &env,
+            (
+                String::from_str(&env, "bonus"),
+                I256::from_i128(&env, DECIMALS / 2),
+            ),
+        ],
+        &LayerAggregator::Sum,
+    );
+    let layer0 = String::from_str(&env, "0");
+    let layer1 = String::from_str(&env, "1");
+    let layer2 = String::from_str(&env, "2");
+    contract_client.set_layer_inputs(&layer1, &vec![&env, layer0.clone()]);
+    contract_client.set_layer_weight(&layer2, &I256::from_i128(&env, 2 * DECIMALS));
+    contract_client.set_voting_power_policy(&VotingPowerPolicy {
+        max_power: Some(I256::from_i128(&env, 30 * DECIMALS)),
+        ..VotingPowerPolicy::default()
+    });
+
+    set_neuron_value(&env, &contract_client, "0", "trust", 2 * DECIMALS);
+    set_neuron_value(&env, &contract_client, "0", "reputation", 3 * DECIMALS);
+    set_neuron_value(&env, &contract_client, "1", "history", 4 * DECIMALS);
+    set_neuron_value(&env, &contract_client, "2", "bonus", 6 * DECIMALS);
+
+    let user1 = String::from_str(&env, "user1");
+    let round = contract_client.get_current_round();
+    assert_eq!(
+        contract_client.try_get_voting_power_breakdown(&round, &user1),
+        Err(Ok(VotingSystemError::VotingPowersNotSet))
+    );
+
+    contract_client.calculate_voting_powers();
+    let breakdown = contract_client.get_voting_power_breakdown(&round, &user1);
+    let neuron = |layer_id: &String, neuron_id: &str, raw: i128, weighted: i128| NeuronBreakdown {
+        layer_id: layer_id.clone(),
+        neuron_id: String::from_str(&env, neuron_id),
+        raw_value: I256::from_i128(&env, raw * DECIMALS),
+        weighted_value: I256::from_i128(&env, weighted * DECIMALS),
+    };
+    assert_eq!(
+        breakdown.neurons,
+        vec![
+            &env,
+            neuron(&layer0, "trust", 2, 2),
+            neuron(&layer0, "reputation", 3, 6),
+            neuron(&layer1, "history", 4, 4),
+            neuron(&layer2, "bonus", 6, 3),
+        ]
+    );
+    let layer = |layer_id: &String, value: i128| LayerBreakdown {
+        layer_id: layer_id.clone(),
+        value: I256::from_i128(&env, value * DECIMALS),
+    };
+    assert_eq!(
+        breakdown.layers,
+        vec![
+            &env,
+            layer(&layer0, 8),
+            layer(&layer1, 32),
+            layer(&layer2, 3)
+        ]
+    );
+    assert_eq!(
+        breakdown.combined_power,
+        I256::from_i128(&env, 38 * DECIMALS)
+    );
+    assert_eq!(breakdown.voting_power, I256::from_i128(&env, 30 * DECIMALS));
+    assert_eq!(
+        contract_client.get_voting_powers().get(user1.clone()),
+        Some(breakdown.voting_power)
+    );
+
+    assert_eq!(
+        contract_client.try_get_voting_power_breakdown(&round, &String::from_str(&env, "user2")),
+        Err(Ok(VotingSystemError::NGQResultForVoterMissing))
+    );
+}
+
+#[test]
+fn snapshotting_neural_governance() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_client = deploy_contract(&env);
+
+    let layer_id = String::from_str(&env, "0");
+    let neuron_id = String::from_str(&env, "neuron");
+    contract_client.add_layer(
+        &vec![
+            &env,
+            (neuron_id.clone(), I256::from_i128(&env, 2 * DECIMALS)),
+        ],
+        &LayerAggregator::Sum,
+    );
+    set_neuron_value(&env, &contract_client, "0", "neuron", 3 * DECIMALS);
+
+    let round = contract_client.get_current_round();
+    assert_eq!(
+        contract_client.try_get_neural_governance_round(&round),
+        Err(Ok(VotingSystemError::NeuralGovernanceRoundNotSet))
+    );
+    contract_client.calculate_voting_powers();
+
+    // changing the configuration doesn't affect the stored one
+    contract_client.update_layer(
+        &layer_id,
+        &vec![
+            &env,
+            (neuron_id.clone(), I256::from_i128(&env, 5 * DECIMALS)),
+        ],
+        &LayerAggregator::Product,
+    );
+    contract_client.set_voting_power_policy(&VotingPowerPolicy {
+        max_power: Some(I256::from_i128(&env, DECIMALS)),
+        ..VotingPowerPolicy::default()
+    });
+
+    let neural_governance_round = contract_client.get_neural_governance_round(&round);
+    assert_eq!(
+        neural_governance_round.neural_governance.layers,
+        vec![&env, layer_id.clone()]
+    );
+    assert_eq!(
+        neural_governance_round
+            .layers
+            .get(layer_id.clone())
+            .unwrap()
+            .aggregator,
+        LayerAggregator::Sum
+    );
+    assert_eq!(
+        neural_governance_round
+            .neurons
+            .get(layer_id.clone())
+            .unwrap()
+            .get(neuron_id.clone())
+            .unwrap()
+            .weight,
+        I256::from_i128(&env, 2 * DECIMALS)
+    );
+    assert_eq!(neural_governance_round.policy, VotingPowerPolicy::default());
+
+    // historical voting powers are explained with the stored configuration
+    let breakdown =
+        contract_client.get_voting_power_breakdown(&round, &String::from_str(&env, "user1"));
+    assert_eq!(
+        breakdown.combined_power,
+        I256::from_i128(&env, 6 * DECIMALS)
+    );
+    assert_eq!(breakdown.voting_power, breakdown.combined_power);
+
+    contract_client.calculate_voting_powers();
+    let neural_governance_round = contract_client.get_neural_governance_round(&round);
+    assert_eq!(
+        neural_governance_round
+            .layers
+            .get(layer_id)
+            .unwrap()
+            .aggregator,
+        LayerAggregator::Product
+    );
+    assert_eq!(
+        contract_client
+            .get_voting_powers()
+            .get(String::from_str(&env, "user1")),
+        Some(I256::from_i128(&env, DECIMALS))
+    );
+}
+
+#[test]
+fn simulating_voting_powers() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_client = deploy_contract(&env);
+
+    let layer_id = String::from_str(&env, "0");
+    let neuron_id = String::from_str(&env, "neuron");
+    let user1 = String::from_str(&env, "user1");
+    contract_client.add_layer(
+        &vec![
+            &env,
+            (neuron_id.clone(), I256::from_i128(&env, 2 * DECIMALS)),
+        ],
+        &LayerAggregator::Sum,
+    );
+    set_neuron_value(&env, &contract_client, "0", "neuron", 3 * DECIMALS);
+
+    let simulated = contract_client.simulate_voting_powers(&None);
+    assert_eq!(
+        simulated.get(user1.clone()),
+        Some(I256::from_i128(&env, 6 * DECIMALS))
+    );
+    assert_eq!(
+        contract_client.try_get_voting_powers(),
+        Err(Ok(VotingSystemError::VotingPowersNotSet))
+    );
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(contract_client.get_voting_powers(), simulated);
+
+    // preview a weight change and a policy without applying them
+    let mut config =
+        contract_client.get_neural_governance_round(&contract_client.get_current_round());
+    let mut neurons = config.neurons.get(layer_id.clone()).unwrap();
+    let mut neuron = neurons.get(neuron_id.clone()).unwrap();
+    neuron.weight = I256::from_i128(&env, 5 * DECIMALS);
+    neurons.set(neuron_id.clone(), neuron);
+    config.neurons.set(layer_id.clone(), neurons);
+    assert_eq!(
+        contract_client
+            .simulate_voting_powers(&Some(config.clone()))
+            .get(user1.clone()),
+        Some(I256::from_i128(&env, 15 * DECIMALS))
+    );
+    config.policy.max_power = Some(I256::from_i128(&env, 10 * DECIMALS));
+    assert_eq!(
+        contract_client
+            .simulate_voting_powers(&Some(config.clone()))
+            .get(user1.clone()),
+        Some(I256::from_i128(&env, 10 * DECIMALS))
+    );
+
+    assert_eq!(contract_client.get_voting_powers(), simulated);
+    assert_eq!(
+        contract_client.get_neuron(&layer_id, &neuron_id).weight,
+        I256::from_i128(&env, 2 * DECIMALS)
+    );
+
+    config
+        .layer_inputs
+        .set(layer_id.clone(), vec![&env, layer_id]);
+    assert_eq!(
+        contract_client.try_simulate_voting_powers(&Some(config)),
+        Err(Ok(VotingSystemError::CyclicLayers))
+    );
+}
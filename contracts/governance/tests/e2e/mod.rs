@@ -1,10 +1,24 @@
 #[cfg(feature = "testutils")]
+mod allocation;
+#[cfg(feature = "testutils")]
 mod auth;
 #[cfg(feature = "testutils")]
+mod chunks;
+#[cfg(feature = "testutils")]
+mod claims;
+#[cfg(feature = "testutils")]
 pub(crate) mod common;
 #[cfg(feature = "testutils")]
+mod delegation;
+#[cfg(feature = "testutils")]
+mod events;
+#[cfg(feature = "testutils")]
 mod governance;
 #[cfg(feature = "testutils")]
+mod registry;
+#[cfg(feature = "testutils")]
+mod round;
+#[cfg(feature = "testutils")]
 mod upgrade;
 #[cfg(feature = "testutils")]
 mod voting;
@@ -0,0 +1,209 @@
+use soroban_sdk::testutils::Address as AddressTrait;
+use soroban_sdk::{vec, Address, Env, Map, String, I256};
+
+use governance::types::{TallyResult, Vote, VotingSystemError};
+use governance::VotingSystemClient;
+
+use crate::e2e::common::contract_utils::{
+    deploy_contract, setup_layer, setup_round, setup_submission,
+};
+
+#[test]
+fn registering_eligible_voters() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let (layer0, neuron0) = setup_layer(&env, &contract_client);
+    let submission = setup_submission(&env, &contract_client);
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+    let outsider = String::from_str(&env, "outsider");
+    let voter = Address::generate(&env);
+
+    // rounds without a registry accept every voter
+    assert_eq!(contract_client.get_eligible_voters_count(&25), None);
+    assert!(contract_client.is_eligible_voter(&25, &outsider));
+
+    assert_eq!(
+        contract_client.register_voters(&vec![
+            &env,
+            user1.clone(),
+            user2.clone(),
+            user1.clone(),
+            voter.to_string(),
+        ]),
+        3
+    );
+    assert_eq!(contract_client.get_eligible_voters_count(&25), Some(3));
+    assert!(contract_client.is_eligible_voter(&25, &user1));
+    assert!(!contract_client.is_eligible_voter(&25, &outsider));
+    assert!(contract_client.is_eligible_voter(&24, &outsider));
+
+    let mut votes = Map::new(&env);
+    votes.set(user1.clone(), Vote::Yes);
+    votes.set(outsider.clone(), Vote::Yes);
+    assert_eq!(
+        contract_client.try_set_votes_for_submission(&submission, &votes),
+        Err(Ok(VotingSystemError::VoterNotEligible))
+    );
+    assert_eq!(
+        contract_client.try_append_votes_chunk(&submission, &votes),
+        Err(Ok(VotingSystemError::VoterNotEligible))
+    );
+    votes.remove(outsider.clone());
+    votes.set(user2.clone(), Vote::No);
+    contract_client.set_votes_for_submission(&submission, &votes);
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(user1.clone(), I256::from_i128(&env, 300));
+    neuron_result.set(outsider.clone(), I256::from_i128(&env, 100));
+    assert_eq!(
+        contract_client.try_set_neuron_result(&layer0, &neuron0, &neuron_result),
+        Err(Ok(VotingSystemError::VoterNotEligible))
+    );
+    assert_eq!(
+        contract_client.try_append_neuron_result_chunk(&layer0, &neuron0, &neuron_result),
+        Err(Ok(VotingSystemError::VoterNotEligible))
+    );
+    neuron_result.remove(outsider);
+    neuron_result.set(user2.clone(), I256::from_i128(&env, 100));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+
+    // voters casting votes themselves have to be registered too
+    contract_client.open_voting();
+    assert_eq!(
+        contract_client.unregister_voters(&vec![
+            &env,
+            voter.to_string(),
+            String::from_str(&env, "unknown")
+        ]),
+        2
+    );
+    assert_eq!(
+        contract_client.try_cast_vote(&voter, &submission, &Vote::Yes),
+        Err(Ok(VotingSystemError::VoterNotEligible))
+    );
+    contract_client.register_voters(&vec![&env, voter.to_string()]);
+    contract_client.cast_vote(&voter, &submission, &Vote::Abstain);
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.tally_submission(&submission).score,
+        I256::from_i128(&env, 200)
+    );
+    contract_client.finalize_tally();
+    assert_eq!(
+        contract_client.try_register_voters(&vec![&env, user1]),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+}
+
+fn upload_round(env: &Env, contract_client: &VotingSystemClient) -> String {
+    let mut neuron_result = Map::new(env);
+    neuron_result.set(String::from_str(env, "user1"), I256::from_i128(env, 300));
+    neuron_result.set(String::from_str(env, "user2"), I256::from_i128(env, 100));
+    neuron_result.set(String::from_str(env, "user3"), I256::from_i128(env, 50));
+    let submission = setup_round(env, contract_client, &neuron_result);
+
+    let mut votes = Map::new(env);
+    votes.set(String::from_str(env, "user1"), Vote::Yes);
+    votes.set(String::from_str(env, "user2"), Vote::No);
+    votes.set(String::from_str(env, "user3"), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission, &votes);
+
+    submission
+}
+
+#[test]
+fn registering_voters_after_upload() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let submission = upload_round(&env, &contract_client);
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+
+    // data uploaded before the registry existed is kept, but only registered voters count
+    contract_client.register_voters(&vec![&env, user1.clone(), user2.clone()]);
+    contract_client.calculate_voting_powers();
+
+    let voting_powers = contract_client.get_voting_powers();
+    assert_eq!(voting_powers.len(), 2);
+    assert_eq!(voting_powers.get(user1), Some(I256::from_i128(&env, 300)));
+    assert_eq!(voting_powers.get(user2), Some(I256::from_i128(&env, 100)));
+
+    let result = contract_client.tally_submission(&submission);
+    assert_eq!(result.score, I256::from_i128(&env, 200));
+    assert_eq!(result.voters, 2);
+}
+
+#[test]
+fn unregistering_voters_after_upload() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+    let user3 = String::from_str(&env, "user3");
+
+    let contract_client = deploy_contract(&env);
+    contract_client.register_voters(&vec![&env, user1.clone(), user2.clone(), user3.clone()]);
+    let submission = upload_round(&env, &contract_client);
+
+    contract_client.unregister_voters(&vec![&env, user2.clone()]);
+    contract_client.calculate_voting_powers();
+
+    let voting_powers = contract_client.get_voting_powers();
+    assert_eq!(voting_powers.len(), 2);
+    assert_eq!(voting_powers.get(user2), None);
+    assert_eq!(
+        contract_client.simulate_tally(&submission, &None).score,
+        I256::from_i128(&env, 350)
+    );
+
+    // voters unregistered after the voting powers were calculated are left out of the tally too
+    contract_client.unregister_voters(&vec![&env, user3]);
+    let result = contract_client.tally_submission(&submission);
+    assert_eq!(result.score, I256::from_i128(&env, 300));
+    assert_eq!(result.voters, 1);
+}
+
+#[test]
+fn tallying_eligible_voters_without_neuron_results() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+    let user3 = String::from_str(&env, "user3");
+    let newcomer = String::from_str(&env, "newcomer");
+
+    let contract_client = deploy_contract(&env);
+    contract_client.register_voters(&vec![&env, user1, user2, user3, newcomer.clone()]);
+    let submission = upload_round(&env, &contract_client);
+
+    // the newcomer is eligible but has no neuron results, so their vote counts with zero power
+    let mut votes = contract_client.get_votes_for_submission(&submission);
+    votes.set(newcomer.clone(), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission, &votes);
+    contract_client.calculate_voting_powers();
+    assert_eq!(contract_client.get_voting_powers().get(newcomer), None);
+
+    let expected = TallyResult {
+        score: I256::from_i128(&env, 250),
+        voters: 4,
+        participating_power: I256::from_i128(&env, 450),
+        passed: true,
+    };
+    assert_eq!(contract_client.simulate_tally(&submission, &None), expected);
+    assert_eq!(contract_client.tally_submission(&submission), expected);
+    assert_eq!(contract_client.tally_round(&0, &10), 1);
+    assert_eq!(
+        contract_client.get_tally_results(&25).get(submission),
+        Some(expected)
+    );
+}
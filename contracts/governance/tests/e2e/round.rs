@@ -0,0 +1,270 @@
+use soroban_sdk::{vec, Env, Map, String, I256};
+
+use governance::types::{RoundPhase, Vote, VotingSystemError};
+
+use crate::e2e::common::contract_utils::{deploy_contract, setup_round};
+
+#[test]
+fn round_lifecycle() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(String::from_str(&env, "user1"), I256::from_i128(&env, 10));
+    let submission = setup_round(&env, &contract_client, &neuron_result);
+    assert_eq!(contract_client.get_round_phase(&25), RoundPhase::Setup);
+
+    contract_client.open_voting();
+    assert_eq!(contract_client.get_round_phase(&25), RoundPhase::Voting);
+
+    let mut votes = Map::new(&env);
+    votes.set(String::from_str(&env, "user1"), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission, &votes);
+
+    contract_client.close_voting();
+    assert_eq!(
+        contract_client.get_round_phase(&25),
+        RoundPhase::VotingClosed
+    );
+    assert!(!contract_client.is_voting_open());
+    assert_eq!(
+        contract_client.try_set_votes_for_submission(&submission, &votes),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.get_round_phase(&25),
+        RoundPhase::PowersCalculated
+    );
+
+    // Voting powers can be recalculated before tallying
+    contract_client.calculate_voting_powers();
+
+    // Tallying doesn't require any authorization
+    assert_eq!(
+        contract_client.tally_submission(&submission).score,
+        I256::from_i128(&env, 10)
+    );
+    assert!(env.auths().is_empty());
+    assert_eq!(contract_client.tally_round(&0, &10), 1);
+    assert!(env.auths().is_empty());
+    // ... but doesn't lock the round
+    assert_eq!(
+        contract_client.get_round_phase(&25),
+        RoundPhase::PowersCalculated
+    );
+
+    // Recalculating voting powers drops provisional tally results
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.try_finalize_tally(),
+        Err(Ok(VotingSystemError::TallyResultsNotSet))
+    );
+    contract_client.tally_round(&0, &10);
+    contract_client.finalize_tally();
+    assert_eq!(contract_client.get_round_phase(&25), RoundPhase::Tallied);
+    assert_eq!(
+        contract_client.try_tally_submission(&submission),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    contract_client.finalize_round();
+    assert_eq!(contract_client.get_round_phase(&25), RoundPhase::Finalized);
+
+    // Next round starts in setup phase
+    contract_client.set_current_round(&26);
+    assert_eq!(contract_client.get_round_phase(&26), RoundPhase::Setup);
+    assert_eq!(contract_client.get_round_phase(&25), RoundPhase::Finalized);
+}
+
+#[test]
+fn finalized_round_is_read_only() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(String::from_str(&env, "user1"), I256::from_i128(&env, 10));
+    let submission = setup_round(&env, &contract_client, &neuron_result);
+
+    let mut votes = Map::new(&env);
+    votes.set(String::from_str(&env, "user1"), Vote::No);
+    contract_client.set_votes_for_submission(&submission, &votes);
+    contract_client.calculate_voting_powers();
+    contract_client.tally_submission(&submission);
+    contract_client.finalize_tally();
+    contract_client.finalize_round();
+
+    // Switching rounds does not unlock a finalized round
+    contract_client.set_current_round(&26);
+    contract_client.set_current_round(&25);
+
+    assert_eq!(
+        contract_client.try_set_submissions(&vec![&env]),
+        Err(Ok(VotingSystemError::RoundFinalized))
+    );
+    assert_eq!(
+        contract_client.try_set_votes_for_submission(&submission, &Map::new(&env)),
+        Err(Ok(VotingSystemError::RoundFinalized))
+    );
+    assert_eq!(
+        contract_client.try_set_neuron_result(
+            &String::from_str(&env, "0"),
+            &String::from_str(&env, "neuron"),
+            &Map::new(&env)
+        ),
+        Err(Ok(VotingSystemError::RoundFinalized))
+    );
+    assert_eq!(
+        contract_client.try_calculate_voting_powers(),
+        Err(Ok(VotingSystemError::RoundFinalized))
+    );
+    assert_eq!(
+        contract_client.try_tally_submission(&submission),
+        Err(Ok(VotingSystemError::RoundFinalized))
+    );
+    assert_eq!(
+        contract_client.try_open_voting(),
+        Err(Ok(VotingSystemError::RoundFinalized))
+    );
+
+    assert_eq!(contract_client.get_votes_for_submission(&submission), votes);
+    assert_eq!(
+        contract_client
+            .get_tally_results(&25)
+            .get(submission)
+            .map(|result| result.score),
+        Some(I256::from_i128(&env, -10))
+    );
+}
+
+#[test]
+fn entrypoints_check_round_phase() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(String::from_str(&env, "user1"), I256::from_i128(&env, 10));
+    let submission = setup_round(&env, &contract_client, &neuron_result);
+
+    assert_eq!(
+        contract_client.try_tally_submission(&submission),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_finalize_round(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_finalize_tally(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_close_voting(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    contract_client.open_voting();
+    assert_eq!(
+        contract_client.try_open_voting(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_set_submissions(&vec![&env]),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    let mut votes = Map::new(&env);
+    votes.set(String::from_str(&env, "user1"), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission, &votes);
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.try_set_votes_for_submission(&submission, &Map::new(&env)),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_close_voting(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_finalize_round(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    contract_client.tally_submission(&submission);
+    assert_eq!(
+        contract_client.try_finalize_round(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    contract_client.finalize_tally();
+    assert_eq!(
+        contract_client.try_set_neuron_result(
+            &String::from_str(&env, "0"),
+            &String::from_str(&env, "neuron"),
+            &Map::new(&env)
+        ),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_calculate_voting_powers(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+}
+
+#[test]
+fn changing_neuron_results_drops_voting_powers() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(String::from_str(&env, "user1"), I256::from_i128(&env, 10));
+    let submission = setup_round(&env, &contract_client, &neuron_result);
+
+    let mut votes = Map::new(&env);
+    votes.set(String::from_str(&env, "user1"), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission, &votes);
+    contract_client.calculate_voting_powers();
+    contract_client.tally_submission(&submission);
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(String::from_str(&env, "user1"), I256::from_i128(&env, 20));
+    contract_client.set_neuron_result(
+        &String::from_str(&env, "0"),
+        &String::from_str(&env, "neuron"),
+        &neuron_result,
+    );
+
+    // powers and tallies calculated from the old result are gone
+    assert_eq!(
+        contract_client.get_round_phase(&25),
+        RoundPhase::VotingClosed
+    );
+    assert_eq!(
+        contract_client.try_get_voting_powers(),
+        Err(Ok(VotingSystemError::VotingPowersNotSet))
+    );
+    assert_eq!(
+        contract_client.try_get_neural_governance_round(&25),
+        Err(Ok(VotingSystemError::NeuralGovernanceRoundNotSet))
+    );
+    assert_eq!(
+        contract_client.try_get_tally_results(&25),
+        Err(Ok(VotingSystemError::TallyResultsNotSet))
+    );
+    assert_eq!(
+        contract_client.try_tally_submission(&submission),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.tally_submission(&submission).score,
+        I256::from_i128(&env, 20)
+    );
+}
@@ -1,5 +1,6 @@
 use crate::e2e::common::contract_utils::deploy_contract;
-use soroban_sdk::{Env, Map, String, I256};
+use governance::{LayerAggregator, DECIMALS};
+use soroban_sdk::{vec, Env, Map, String, I256};
 
 mod mock_contract {
     soroban_sdk::contractimport!(file = "../target/wasm32v1-none/release/mocks.wasm");
@@ -27,6 +28,17 @@ fn storage_is_retained_after_upgrade() {
     let contract_client = deploy_contract(&env);
     let address = contract_client.address.clone();
 
+    contract_client.add_layer(
+        &vec![
+            &env,
+            (
+                String::from_str(&env, "TrustGraph"),
+                I256::from_i128(&env, DECIMALS),
+            ),
+        ],
+        &LayerAggregator::Sum,
+    );
+
     // Store data using old impl
     let mut result = Map::new(&env);
     result.set(String::from_str(&env, "user1"), I256::from_i32(&env, 100));
@@ -34,7 +46,7 @@ fn storage_is_retained_after_upgrade() {
 
     contract_client.set_neuron_result(
         &String::from_str(&env, "0"),
-        &String::from_str(&env, "0"),
+        &String::from_str(&env, "TrustGraph"),
         &result,
     );
 
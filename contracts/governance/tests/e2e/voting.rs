@@ -1,6 +1,12 @@
-use soroban_sdk::{vec, Env, Map, String, Vec, I256};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::Address as AddressTrait;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, BytesN, Env, InvokeError, Map, String, Vec, I256};
 
-use governance::types::{Vote, VotingSystemError};
+use governance::types::{
+    CategoryBudget, RoundPhase, SignedVote, Submission, SubmissionCategory, TallyMode, TallyResult,
+    TallyRules, Vote, VotingMode, VotingSystemError,
+};
 use governance::{LayerAggregator, DECIMALS};
 
 use crate::e2e::common::contract_utils::deploy_contract;
@@ -31,8 +37,8 @@ fn voting_data_upload() {
 
     contract_client.set_submissions(&vec![
         &env,
-        (submission1.clone(), String::from_str(&env, "Applications")),
-        (submission2.clone(), String::from_str(&env, "Applications")),
+        Submission::new(submission1.clone(), SubmissionCategory::Applications),
+        Submission::new(submission2.clone(), SubmissionCategory::Applications),
     ]);
 
     let mut votes_submission1 = Map::new(&env);
@@ -51,8 +57,8 @@ fn voting_data_upload() {
 
     contract_client.set_submissions(&vec![
         &env,
-        (submission1.clone(), String::from_str(&env, "Applications")),
-        (submission2.clone(), String::from_str(&env, "Applications")),
+        Submission::new(submission1.clone(), SubmissionCategory::Applications),
+        Submission::new(submission2.clone(), SubmissionCategory::Applications),
     ]);
 
     let mut neuron_result = Map::new(&env);
@@ -67,18 +73,18 @@ fn voting_data_upload() {
 
     contract_client.set_neuron_result(
         &String::from_str(&env, "0"),
-        &String::from_str(&env, "0"),
+        &String::from_str(&env, "Dummy"),
         &neuron_result,
     );
     contract_client.set_neuron_result(
         &String::from_str(&env, "0"),
-        &String::from_str(&env, "1"),
+        &String::from_str(&env, "TrustGraph"),
         &neuron_result2,
     );
 
     env.cost_estimate().budget().reset_default();
     contract_client.calculate_voting_powers();
-    let result = contract_client.tally_submission(&submission1);
+    let result = contract_client.tally_submission(&submission1).score;
     println!("{}", env.cost_estimate().budget());
 
     assert_eq!(
@@ -90,7 +96,7 @@ fn voting_data_upload() {
     );
 
     env.cost_estimate().budget().reset_default();
-    let result2 = contract_client.tally_submission(&submission2);
+    let result2 = contract_client.tally_submission(&submission2).score;
     println!("{}", env.cost_estimate().budget());
 
     assert_eq!(
@@ -123,24 +129,28 @@ fn adding_duplicate_submissions() {
 
     contract_client.set_submissions(&vec![
         &env,
-        (
+        Submission::new(
             String::from_str(&env, "a"),
-            String::from_str(&env, "Applications"),
+            SubmissionCategory::Applications,
         ),
-        (
+        Submission::new(
             String::from_str(&env, "a"),
-            String::from_str(&env, "Applications"),
+            SubmissionCategory::Applications,
         ),
     ]);
 
     let submissions = contract_client.get_submissions();
     let mut expected = Vec::new(&env);
-    expected.push_back((
+    expected.push_back(Submission::new(
         String::from_str(&env, "a"),
-        String::from_str(&env, "Applications"),
+        SubmissionCategory::Applications,
     ));
 
     assert_eq!(submissions, expected);
+
+    // typed submissions are left as they are
+    assert_eq!(contract_client.migrate_submissions(&25), 1);
+    assert_eq!(contract_client.get_submissions(), expected);
 }
 
 #[test]
@@ -186,7 +196,7 @@ fn set_bump_round_flow() {
     // Set votes and results for round 25
     contract_client.set_submissions(&vec![
         &env,
-        (submission.clone(), String::from_str(&env, "Applications")),
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
     ]);
 
     let mut votes25 = Map::new(&env);
@@ -214,7 +224,7 @@ fn set_bump_round_flow() {
     assert!(contract_client
         .get_submissions()
         .iter()
-        .any(|(name, _category)| name == submission));
+        .any(|active| active.id == submission));
 
     // Bump the round
     contract_client.set_current_round(&26);
@@ -239,10 +249,7 @@ fn set_bump_round_flow() {
     let new_submission = String::from_str(&env, "sub2");
     contract_client.set_submissions(&vec![
         &env,
-        (
-            new_submission.clone(),
-            String::from_str(&env, "Applications"),
-        ),
+        Submission::new(new_submission.clone(), SubmissionCategory::Applications),
     ]);
 
     let mut votes26 = Map::new(&env);
@@ -270,11 +277,11 @@ fn set_bump_round_flow() {
     assert!(contract_client
         .get_submissions()
         .iter()
-        .any(|(name, _category)| name == new_submission));
+        .any(|active| active.id == new_submission));
     assert!(!contract_client
         .get_submissions()
         .iter()
-        .any(|(name, _category)| name == submission));
+        .any(|active| active.id == submission));
 
     // Verify historical results are still accessible
     assert_eq!(
@@ -286,3 +293,784 @@ fn set_bump_round_flow() {
         result25
     );
 }
+
+#[test]
+fn casting_votes() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let neuron0 = String::from_str(&env, "0");
+    let layer0 = String::from_str(&env, "0");
+    contract_client.add_layer(
+        &vec![&env, (neuron0.clone(), I256::from_i128(&env, DECIMALS))],
+        &LayerAggregator::Sum,
+    );
+
+    let submission = String::from_str(&env, "sub1");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
+    ]);
+
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let voter3 = Address::generate(&env);
+
+    assert!(!contract_client.is_voting_open());
+    contract_client.open_voting();
+    assert!(contract_client.is_voting_open());
+
+    contract_client.cast_vote(&voter1, &submission, &Vote::Yes);
+    contract_client.cast_vote(&voter2, &submission, &Vote::Yes);
+    contract_client.cast_vote(&voter3, &submission, &Vote::Abstain);
+
+    // Change a vote while voting is still open
+    contract_client.cast_vote(&voter2, &submission, &Vote::No);
+
+    let votes = contract_client.get_votes_for_submission(&submission);
+    assert_eq!(votes.len(), 3);
+    assert_eq!(votes.get(voter1.to_string()), Some(Vote::Yes));
+    assert_eq!(votes.get(voter2.to_string()), Some(Vote::No));
+    assert_eq!(votes.get(voter3.to_string()), Some(Vote::Abstain));
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(voter1.to_string(), I256::from_i128(&env, 300));
+    neuron_result.set(voter2.to_string(), I256::from_i128(&env, 100));
+    neuron_result.set(voter3.to_string(), I256::from_i128(&env, 50));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.tally_submission(&submission).score,
+        I256::from_i128(&env, 300 - 100)
+    );
+}
+
+fn commitment(
+    env: &Env,
+    voter: &Address,
+    votes: &Map<String, Vote>,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let data = (25_u32, voter.clone(), votes.clone(), salt.clone()).to_xdr(env);
+    env.crypto().sha256(&data).into()
+}
+
+#[test]
+fn commit_reveal_voting() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let neuron0 = String::from_str(&env, "0");
+    let layer0 = String::from_str(&env, "0");
+    contract_client.add_layer(
+        &vec![&env, (neuron0.clone(), I256::from_i128(&env, DECIMALS))],
+        &LayerAggregator::Sum,
+    );
+
+    let sub1 = String::from_str(&env, "sub1");
+    let sub2 = String::from_str(&env, "sub2");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(sub1.clone(), SubmissionCategory::Applications),
+        Submission::new(sub2.clone(), SubmissionCategory::Applications),
+    ]);
+    contract_client.set_voting_mode(&VotingMode::CommitReveal);
+    assert_eq!(
+        contract_client.get_voting_mode(&25),
+        VotingMode::CommitReveal
+    );
+
+    // votes are not uploaded or cast in commit-reveal rounds
+    assert_eq!(
+        contract_client.try_set_votes_for_submission(&sub1, &Map::new(&env)),
+        Err(Ok(VotingSystemError::InvalidVotingMode))
+    );
+    contract_client.open_voting();
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let voter3 = Address::generate(&env);
+    assert_eq!(
+        contract_client.try_cast_vote(&voter1, &sub1, &Vote::Yes),
+        Err(Ok(VotingSystemError::InvalidVotingMode))
+    );
+
+    let salt1 = BytesN::from_array(&env, &[1; 32]);
+    let salt2 = BytesN::from_array(&env, &[2; 32]);
+    let salt3 = BytesN::from_array(&env, &[3; 32]);
+    let mut votes1 = Map::new(&env);
+    votes1.set(sub1.clone(), Vote::Yes);
+    votes1.set(sub2.clone(), Vote::No);
+    let mut votes2 = Map::new(&env);
+    votes2.set(sub1.clone(), Vote::No);
+    let mut votes3 = Map::new(&env);
+    votes3.set(sub1.clone(), Vote::Yes);
+
+    contract_client.commit_votes(&voter1, &commitment(&env, &voter1, &votes1, &salt1));
+    contract_client.commit_votes(&voter2, &commitment(&env, &voter2, &votes2, &salt2));
+    contract_client.commit_votes(&voter3, &commitment(&env, &voter3, &votes3, &salt3));
+
+    // votes can't be revealed until the commit window closes
+    assert_eq!(
+        contract_client.try_reveal_votes(&voter1, &votes1, &salt1),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    // and stay hidden until then
+    assert_eq!(
+        contract_client.try_get_votes_for_submission(&sub1),
+        Err(Ok(VotingSystemError::VotesForSubmissionNotSet))
+    );
+
+    // voting in commit-reveal rounds closes after the reveal, committed votes can't be skipped
+    assert_eq!(
+        contract_client.try_close_voting(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    assert_eq!(
+        contract_client.try_calculate_voting_powers(),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+    contract_client.open_reveal();
+    assert_eq!(
+        contract_client.try_commit_votes(&voter1, &salt1),
+        Err(Ok(VotingSystemError::VotingClosed))
+    );
+
+    contract_client.reveal_votes(&voter1, &votes1, &salt1);
+    assert_eq!(contract_client.get_vote_commitment(&25, &voter1), None);
+    assert_eq!(
+        contract_client.try_reveal_votes(&voter1, &votes1, &salt1),
+        Err(Ok(VotingSystemError::VoteCommitmentNotSet))
+    );
+
+    // votes and salt have to match the commitment
+    let mut changed = votes2.clone();
+    changed.set(sub1.clone(), Vote::Yes);
+    assert_eq!(
+        contract_client.try_reveal_votes(&voter2, &changed, &salt2),
+        Err(Ok(VotingSystemError::InvalidVoteCommitment))
+    );
+    assert_eq!(
+        contract_client.try_reveal_votes(&voter2, &votes2, &salt1),
+        Err(Ok(VotingSystemError::InvalidVoteCommitment))
+    );
+    contract_client.reveal_votes(&voter2, &votes2, &salt2);
+
+    // voter3 never reveals
+    contract_client.close_voting();
+    assert_eq!(
+        contract_client.get_round_phase(&25),
+        RoundPhase::VotingClosed
+    );
+    assert_eq!(
+        contract_client.try_reveal_votes(&voter1, &votes1, &salt1),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(voter1.to_string(), I256::from_i128(&env, 300));
+    neuron_result.set(voter2.to_string(), I256::from_i128(&env, 100));
+    neuron_result.set(voter3.to_string(), I256::from_i128(&env, 1000));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.tally_submission(&sub1).score,
+        I256::from_i128(&env, 300 - 100)
+    );
+    assert_eq!(
+        contract_client.tally_submission(&sub2).score,
+        I256::from_i128(&env, -300)
+    );
+    assert!(contract_client.get_vote_commitment(&25, &voter3).is_some());
+}
+
+#[test]
+fn opening_reveal_in_open_round() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    contract_client.open_voting();
+
+    assert_eq!(
+        contract_client.try_open_reveal(),
+        Err(Ok(VotingSystemError::InvalidVotingMode))
+    );
+    assert_eq!(
+        contract_client.try_commit_votes(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[0; 32])
+        ),
+        Err(Ok(VotingSystemError::InvalidVotingMode))
+    );
+    // the voting mode is set during setup only
+    assert_eq!(
+        contract_client.try_set_voting_mode(&VotingMode::CommitReveal),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+}
+
+#[test]
+fn tallying_with_rules() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let neuron0 = String::from_str(&env, "0");
+    let layer0 = String::from_str(&env, "0");
+    contract_client.add_layer(
+        &vec![&env, (neuron0.clone(), I256::from_i128(&env, DECIMALS))],
+        &LayerAggregator::Sum,
+    );
+
+    let submission1 = String::from_str(&env, "submission1");
+    let submission2 = String::from_str(&env, "submission2");
+    let submission3 = String::from_str(&env, "submission3");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission1.clone(), SubmissionCategory::Applications),
+        Submission::new(submission2.clone(), SubmissionCategory::Applications),
+        Submission::new(submission3.clone(), SubmissionCategory::Applications),
+    ]);
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+    let user3 = String::from_str(&env, "user3");
+    for (submission, votes) in [
+        (&submission1, [(&user1, Vote::Yes), (&user2, Vote::No)]),
+        (&submission2, [(&user1, Vote::Yes), (&user3, Vote::Abstain)]),
+        (&submission3, [(&user2, Vote::Yes), (&user3, Vote::Abstain)]),
+    ] {
+        let mut submission_votes = Map::new(&env);
+        for (voter, vote) in votes {
+            submission_votes.set(voter.clone(), vote);
+        }
+        contract_client.set_votes_for_submission(submission, &submission_votes);
+    }
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(user1, I256::from_i128(&env, 300));
+    neuron_result.set(user2, I256::from_i128(&env, 100));
+    neuron_result.set(user3, I256::from_i128(&env, 50));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+
+    let rules = TallyRules {
+        min_voters: 2,
+        min_power: Some(I256::from_i128(&env, 200)),
+        no_vote_multiplier: Some(I256::from_i128(&env, 2 * DECIMALS)),
+        count_abstain: true,
+        mode: TallyMode::Linear,
+    };
+    assert_eq!(
+        contract_client.try_set_tally_rules(&TallyRules {
+            no_vote_multiplier: Some(I256::from_i128(&env, -1)),
+            ..rules.clone()
+        }),
+        Err(Ok(VotingSystemError::InvalidTallyRules))
+    );
+    contract_client.set_tally_rules(&rules);
+    assert_eq!(contract_client.get_tally_rules(&25), rules);
+    assert_eq!(contract_client.get_tally_rules(&24), TallyRules::default());
+
+    contract_client.calculate_voting_powers();
+    contract_client.tally_round(&0, &u32::MAX);
+
+    let tally_results = contract_client.get_tally_results(&25);
+    assert_eq!(
+        tally_results.get(submission1.clone()),
+        Some(TallyResult {
+            score: I256::from_i128(&env, 300 - 2 * 100),
+            voters: 2,
+            participating_power: I256::from_i128(&env, 400),
+            passed: true,
+        })
+    );
+    assert_eq!(
+        tally_results.get(submission2.clone()),
+        Some(TallyResult {
+            score: I256::from_i128(&env, 300),
+            voters: 2,
+            participating_power: I256::from_i128(&env, 350),
+            passed: true,
+        })
+    );
+    // not enough voting power
+    assert_eq!(
+        tally_results.get(submission3.clone()),
+        Some(TallyResult {
+            score: I256::from_i128(&env, 100),
+            voters: 2,
+            participating_power: I256::from_i128(&env, 150),
+            passed: false,
+        })
+    );
+
+    contract_client.finalize_tally();
+    assert_eq!(
+        contract_client.try_set_tally_rules(&TallyRules::default()),
+        Err(Ok(VotingSystemError::InvalidRoundPhase))
+    );
+
+    // only passed submissions are awarded
+    contract_client.set_category_budget(
+        &String::from_str(&env, "Applications"),
+        &CategoryBudget {
+            amount: 900,
+            top_n: None,
+            min_score: None,
+            max_award: None,
+            pro_rata: false,
+        },
+    );
+    let allocations = contract_client.get_allocations(&25);
+    assert_eq!(allocations.len(), 2);
+    assert_eq!(allocations.get(submission1), Some(450));
+    assert_eq!(allocations.get(submission2), Some(450));
+}
+
+#[test]
+fn tallying_in_budgeted_mode() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let neuron0 = String::from_str(&env, "0");
+    let layer0 = String::from_str(&env, "0");
+    contract_client.add_layer(
+        &vec![&env, (neuron0.clone(), I256::from_i128(&env, DECIMALS))],
+        &LayerAggregator::Sum,
+    );
+
+    let submission1 = String::from_str(&env, "submission1");
+    let submission2 = String::from_str(&env, "submission2");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission1.clone(), SubmissionCategory::Applications),
+        Submission::new(submission2.clone(), SubmissionCategory::DeveloperTooling),
+    ]);
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+    for (submission, votes) in [
+        (&submission1, [(&user1, Vote::Yes), (&user2, Vote::Yes)]),
+        (&submission2, [(&user1, Vote::Yes), (&user2, Vote::No)]),
+    ] {
+        let mut submission_votes = Map::new(&env);
+        for (voter, vote) in votes {
+            submission_votes.set(voter.clone(), vote);
+        }
+        contract_client.set_votes_for_submission(submission, &submission_votes);
+    }
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(user1, I256::from_i128(&env, 300));
+    neuron_result.set(user2, I256::from_i128(&env, 100));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+
+    contract_client.set_tally_rules(&TallyRules {
+        mode: TallyMode::Budgeted,
+        ..TallyRules::default()
+    });
+    contract_client.calculate_voting_powers();
+
+    // user1 splits their voting power between both submissions
+    assert_eq!(
+        contract_client.tally_submission(&submission2).score,
+        I256::from_i128(&env, 300 / 2 - 100)
+    );
+    contract_client.tally_round(&0, &u32::MAX);
+    assert_eq!(
+        contract_client
+            .get_tally_results(&25)
+            .get(submission1)
+            .map(|result| result.score),
+        Some(I256::from_i128(&env, 300 / 2 + 100))
+    );
+}
+
+#[test]
+fn simulating_tally() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let neuron0 = String::from_str(&env, "0");
+    let layer0 = String::from_str(&env, "0");
+    contract_client.add_layer(
+        &vec![&env, (neuron0.clone(), I256::from_i128(&env, DECIMALS))],
+        &LayerAggregator::Sum,
+    );
+
+    let submission = String::from_str(&env, "submission");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
+    ]);
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+    let mut votes = Map::new(&env);
+    votes.set(user1.clone(), Vote::Yes);
+    votes.set(user2.clone(), Vote::No);
+    contract_client.set_votes_for_submission(&submission, &votes);
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(user1.clone(), I256::from_i128(&env, 300));
+    neuron_result.set(user2.clone(), I256::from_i128(&env, 100));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+
+    assert_eq!(
+        contract_client.try_simulate_tally(&submission, &None),
+        Err(Ok(VotingSystemError::VotingPowersNotSet))
+    );
+    let mut powers = Map::new(&env);
+    powers.set(user1, I256::from_i128(&env, 50));
+    powers.set(user2, I256::from_i128(&env, 100));
+    let result = contract_client.simulate_tally(&submission, &Some(powers));
+    assert_eq!(result.score, I256::from_i128(&env, -50));
+    assert!(!result.passed);
+
+    contract_client.calculate_voting_powers();
+    assert_eq!(
+        contract_client.simulate_tally(&submission, &None).score,
+        I256::from_i128(&env, 200)
+    );
+
+    // nothing is stored
+    assert_eq!(
+        contract_client.try_get_tally_results(&25),
+        Err(Ok(VotingSystemError::TallyResultsNotSet))
+    );
+    assert_eq!(
+        contract_client.get_round_phase(&25),
+        RoundPhase::PowersCalculated
+    );
+}
+
+fn sign_vote(
+    env: &Env,
+    contract: &Address,
+    key: &SigningKey,
+    submission_id: &String,
+    vote: Vote,
+    nonce: u64,
+) -> SignedVote {
+    let message = (contract.clone(), 25_u32, submission_id.clone(), vote, nonce).to_xdr(env);
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    SignedVote {
+        public_key: BytesN::from_array(env, &key.verifying_key().to_bytes()),
+        vote,
+        nonce,
+        signature: BytesN::from_array(env, &key.sign(&message).to_bytes()),
+    }
+}
+
+fn account_id(env: &Env, key: &SigningKey) -> String {
+    let strkey = stellar_strkey::ed25519::PublicKey(key.verifying_key().to_bytes()).to_string();
+    String::from_str(env, &strkey)
+}
+
+#[test]
+fn uploading_signed_votes() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    let contract = contract_client.address.clone();
+
+    let submission = String::from_str(&env, "sub1");
+    let other_submission = String::from_str(&env, "sub2");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
+        Submission::new(other_submission.clone(), SubmissionCategory::Applications),
+    ]);
+
+    let key1 = SigningKey::from_bytes(&[1; 32]);
+    let key2 = SigningKey::from_bytes(&[2; 32]);
+    contract_client.upload_signed_votes(
+        &submission,
+        &vec![
+            &env,
+            sign_vote(&env, &contract, &key1, &submission, Vote::Yes, 1),
+            sign_vote(&env, &contract, &key2, &submission, Vote::No, 7),
+        ],
+    );
+
+    let votes = contract_client.get_votes_for_submission(&submission);
+    assert_eq!(votes.len(), 2);
+    assert_eq!(votes.get(account_id(&env, &key1)), Some(Vote::Yes));
+    assert_eq!(votes.get(account_id(&env, &key2)), Some(Vote::No));
+    let voter1 = Address::from_string(&account_id(&env, &key1));
+    assert_eq!(
+        contract_client.get_vote_nonce(&25, &submission, &voter1),
+        Some(1)
+    );
+
+    // replaying a signed vote is rejected
+    assert_eq!(
+        contract_client.try_upload_signed_votes(
+            &submission,
+            &vec![
+                &env,
+                sign_vote(&env, &contract, &key2, &submission, Vote::No, 8),
+                sign_vote(&env, &contract, &key1, &submission, Vote::Yes, 1),
+            ],
+        ),
+        Err(Ok(VotingSystemError::VoteNonceUsed))
+    );
+    assert_eq!(
+        contract_client.get_vote_nonce(&25, &submission, &voter1),
+        Some(1)
+    );
+
+    // the voter changes their vote with a new nonce
+    contract_client.upload_signed_votes(
+        &submission,
+        &vec![
+            &env,
+            sign_vote(&env, &contract, &key1, &submission, Vote::No, 2),
+        ],
+    );
+    assert_eq!(
+        contract_client
+            .get_votes_for_submission(&submission)
+            .get(account_id(&env, &key1)),
+        Some(Vote::No)
+    );
+
+    // a vote signed for one submission can't be used for another one
+    let signed = sign_vote(&env, &contract, &key1, &submission, Vote::Yes, 3);
+    assert!(contract_client
+        .try_upload_signed_votes(&other_submission, &vec![&env, signed.clone()])
+        .is_err());
+
+    // and a vote can't be changed without the voter signing it; the host traps on an invalid
+    // signature instead of returning a contract error, and the valid votes of the batch are
+    // discarded with it
+    let forged = SignedVote {
+        vote: Vote::No,
+        ..signed
+    };
+    assert_eq!(
+        contract_client.try_upload_signed_votes(
+            &submission,
+            &vec![
+                &env,
+                sign_vote(&env, &contract, &key2, &submission, Vote::Yes, 9),
+                forged,
+            ],
+        ),
+        Err(Err(InvokeError::Abort))
+    );
+    assert_eq!(
+        contract_client
+            .get_votes_for_submission(&submission)
+            .get(account_id(&env, &key2)),
+        Some(Vote::No)
+    );
+    assert_eq!(
+        contract_client.get_vote_nonce(&25, &submission, &voter1),
+        Some(2)
+    );
+}
+
+#[test]
+fn casting_vote_when_voting_is_closed() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let submission = String::from_str(&env, "sub1");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
+    ]);
+
+    let voter = Address::generate(&env);
+    assert_eq!(
+        contract_client.try_cast_vote(&voter, &submission, &Vote::Yes),
+        Err(Ok(VotingSystemError::VotingClosed))
+    );
+
+    contract_client.open_voting();
+    contract_client.cast_vote(&voter, &submission, &Vote::Yes);
+    // Calculating voting powers closes voting
+    contract_client.calculate_voting_powers();
+    assert!(!contract_client.is_voting_open());
+
+    assert_eq!(
+        contract_client.try_cast_vote(&voter, &submission, &Vote::No),
+        Err(Ok(VotingSystemError::VotingClosed))
+    );
+    assert_eq!(
+        contract_client
+            .get_votes_for_submission(&submission)
+            .get(voter.to_string()),
+        Some(Vote::Yes)
+    );
+
+    // Voting window is tracked per round
+    contract_client.set_current_round(&26);
+    assert!(!contract_client.is_voting_open());
+}
+
+#[test]
+fn casting_vote_for_unknown_submission() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+    contract_client.open_voting();
+
+    assert_eq!(
+        contract_client.try_cast_vote(
+            &Address::generate(&env),
+            &String::from_str(&env, "sub1"),
+            &Vote::Yes
+        ),
+        Err(Ok(VotingSystemError::SubmissionDoesNotExist))
+    );
+}
+
+#[test]
+fn cast_votes_override_uploaded_votes() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let submission = String::from_str(&env, "sub1");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission.clone(), SubmissionCategory::Applications),
+    ]);
+
+    let voter = Address::generate(&env);
+    let user = String::from_str(&env, "user");
+
+    let mut uploaded = Map::new(&env);
+    uploaded.set(voter.to_string(), Vote::No);
+    uploaded.set(user.clone(), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission, &uploaded);
+
+    contract_client.open_voting();
+    contract_client.cast_vote(&voter, &submission, &Vote::Yes);
+
+    let votes = contract_client.get_votes_for_submission(&submission);
+    assert_eq!(votes.len(), 2);
+    assert_eq!(votes.get(voter.to_string()), Some(Vote::Yes));
+    assert_eq!(votes.get(user), Some(Vote::Yes));
+}
+
+#[test]
+fn tallying_round_in_pages() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_client = deploy_contract(&env);
+
+    let neuron0 = String::from_str(&env, "0");
+    let layer0 = String::from_str(&env, "0");
+    contract_client.add_layer(
+        &vec![&env, (neuron0.clone(), I256::from_i128(&env, DECIMALS))],
+        &LayerAggregator::Sum,
+    );
+
+    let applications = String::from_str(&env, "Applications");
+    let tooling = String::from_str(&env, "DeveloperTooling");
+    let submission1 = String::from_str(&env, "submission1");
+    let submission2 = String::from_str(&env, "submission2");
+    let submission3 = String::from_str(&env, "submission3");
+    let no_votes = String::from_str(&env, "no_votes");
+    contract_client.set_submissions(&vec![
+        &env,
+        Submission::new(submission1.clone(), SubmissionCategory::Applications),
+        Submission::new(submission2.clone(), SubmissionCategory::DeveloperTooling),
+        Submission::new(submission3.clone(), SubmissionCategory::Applications),
+        Submission::new(no_votes.clone(), SubmissionCategory::Applications),
+    ]);
+
+    let user1 = String::from_str(&env, "user1");
+    let user2 = String::from_str(&env, "user2");
+
+    let mut votes1 = Map::new(&env);
+    votes1.set(user1.clone(), Vote::Yes);
+    votes1.set(user2.clone(), Vote::No);
+    contract_client.set_votes_for_submission(&submission1, &votes1);
+
+    let mut votes2 = Map::new(&env);
+    votes2.set(user1.clone(), Vote::No);
+    contract_client.set_votes_for_submission(&submission2, &votes2);
+
+    let mut votes3 = Map::new(&env);
+    votes3.set(user1.clone(), Vote::Yes);
+    votes3.set(user2.clone(), Vote::Yes);
+    contract_client.set_votes_for_submission(&submission3, &votes3);
+
+    let mut neuron_result = Map::new(&env);
+    neuron_result.set(user1.clone(), I256::from_i128(&env, 300));
+    neuron_result.set(user2.clone(), I256::from_i128(&env, 100));
+    contract_client.set_neuron_result(&layer0, &neuron0, &neuron_result);
+    contract_client.calculate_voting_powers();
+
+    assert_eq!(contract_client.tally_round(&0, &3), 3);
+    assert_eq!(contract_client.get_tally_results(&25).len(), 3);
+    assert_eq!(contract_client.tally_round(&3, &3), 4);
+    assert_eq!(contract_client.tally_round(&4, &3), 4);
+
+    let tally_results = contract_client.get_tally_results(&25);
+    assert_eq!(tally_results.len(), 4);
+    assert_eq!(
+        tally_results
+            .get(submission1.clone())
+            .map(|result| result.score),
+        Some(I256::from_i128(&env, 200))
+    );
+    assert_eq!(
+        tally_results
+            .get(submission2.clone())
+            .map(|result| result.score),
+        Some(I256::from_i128(&env, -300))
+    );
+    assert_eq!(
+        tally_results
+            .get(submission3.clone())
+            .map(|result| result.score),
+        Some(I256::from_i128(&env, 400))
+    );
+    assert_eq!(
+        tally_results
+            .get(no_votes.clone())
+            .map(|result| result.score),
+        Some(I256::from_i128(&env, 0))
+    );
+
+    // Tallying the whole round in one call gives the same results
+    assert_eq!(contract_client.tally_round(&0, &u32::MAX), 4);
+    assert_eq!(contract_client.get_tally_results(&25), tally_results);
+
+    let ranked = contract_client.get_ranked_results(&25);
+    assert_eq!(
+        ranked.get(applications).unwrap(),
+        vec![
+            &env,
+            (submission3, I256::from_i128(&env, 400)),
+            (submission1, I256::from_i128(&env, 200)),
+            (no_votes, I256::from_i128(&env, 0)),
+        ]
+    );
+    assert_eq!(
+        ranked.get(tooling).unwrap(),
+        vec![&env, (submission2, I256::from_i128(&env, -300))]
+    );
+}
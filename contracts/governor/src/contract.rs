@@ -376,7 +376,7 @@ mod test {
         let mut result = governance_client
             .try_get_neuron_result(
                 &soroban_sdk::String::from_str(env, "0"),
-                &soroban_sdk::String::from_str(env, "0"),
+                &soroban_sdk::String::from_str(env, "Layer1"),
             )
             .unwrap_or_else(|_| {
                 let mut map = Map::new(env);
@@ -388,7 +388,7 @@ mod test {
 
         governance_client.set_neuron_result(
             &soroban_sdk::String::from_str(env, "0"),
-            &soroban_sdk::String::from_str(env, "0"),
+            &soroban_sdk::String::from_str(env, "Layer1"),
             &result,
         );
 
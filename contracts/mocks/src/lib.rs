@@ -28,9 +28,9 @@ impl MockContract {
     pub fn get_stored_neuron_result(env: &Env) -> Map<String, I256> {
         let key = DataKey::NeuronResultKey(NeuronResultKeyData {
             layer_id: String::from_str(env, "0"),
-            neuron_id: String::from_str(env, "0"),
+            neuron_id: String::from_str(env, "TrustGraph"),
             round: 25,
         });
-        env.storage().temporary().get(&key).unwrap()
+        env.storage().persistent().get(&key).unwrap()
     }
 }
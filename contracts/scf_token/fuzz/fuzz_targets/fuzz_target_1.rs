@@ -58,7 +58,7 @@ pub fn deploy_and_setup<'a>(env: &Env, admin: &Address) -> Deployment<'a> {
 
     governance_client.set_neuron_result(
         &soroban_sdk::String::from_str(env, "0"),
-        &soroban_sdk::String::from_str(env, "0"),
+        &soroban_sdk::String::from_str(env, "Layer1"),
         &result,
     );
 
@@ -85,13 +85,13 @@ pub fn update_balance(
 ) {
     let mut result = governance_client.get_neuron_result(
         &soroban_sdk::String::from_str(env, "0"),
-        &soroban_sdk::String::from_str(env, "0"),
+        &soroban_sdk::String::from_str(env, "Layer1"),
     );
     result.set(address.to_string(), I256::from_i128(env, new_balance));
 
     governance_client.set_neuron_result(
         &soroban_sdk::String::from_str(env, "0"),
-        &soroban_sdk::String::from_str(env, "0"),
+        &soroban_sdk::String::from_str(env, "Layer1"),
         &result,
     );
 
@@ -9,6 +9,27 @@ pub(crate) fn write_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&DataKey::Admin, &admin);
 }
 
+pub(crate) fn read_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub(crate) fn write_pending_admin(env: &Env, pending_admin: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, pending_admin);
+}
+
+pub(crate) fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
 pub(crate) trait Admin {
+    /// Propose transferring the admin to `new_admin`, who has to accept it with `accept_admin`.
     fn transfer_admin(env: Env, new_admin: Address);
+    /// Accept the admin proposed by `transfer_admin`, authorized by the pending admin.
+    fn accept_admin(env: Env);
+    /// Cancel a pending transfer of the admin.
+    fn cancel_admin_transfer(env: Env);
+    /// Get the address the admin is being transferred to, if any.
+    fn get_pending_admin(env: Env) -> Option<Address>;
 }
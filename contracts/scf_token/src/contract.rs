@@ -1,4 +1,6 @@
-use crate::admin::{read_admin, write_admin, Admin};
+use crate::admin::{
+    read_admin, read_pending_admin, remove_pending_admin, write_admin, write_pending_admin, Admin,
+};
 use soroban_sdk::token::Interface;
 use soroban_sdk::{
     assert_with_error, contract, contractimpl, panic_with_error, Address, BytesN, Env,
@@ -6,6 +8,7 @@ use soroban_sdk::{
 };
 
 use crate::balance::{extend_balance, read_balance, write_balance};
+use crate::events::TokenEvents;
 use crate::storage::{
     read_all_addresses, read_governance_contract_address, read_total_supply, update_all_addresses,
     write_governance_contract_address, write_total_supply,
@@ -203,7 +206,34 @@ impl Admin for SCFToken {
         let admin = read_admin(&env);
         admin.require_auth();
 
+        write_pending_admin(&env, &new_admin);
+        TokenEvents::admin_transfer_proposed(&env, new_admin);
+    }
+
+    fn accept_admin(env: Env) {
+        let Some(new_admin) = read_pending_admin(&env) else {
+            panic_with_error!(env, ContractError::NoPendingAdmin);
+        };
+        new_admin.require_auth();
+
         write_admin(&env, &new_admin);
+        remove_pending_admin(&env);
+        TokenEvents::admin_transferred(&env, new_admin);
+    }
+
+    fn cancel_admin_transfer(env: Env) {
+        let admin = read_admin(&env);
+        admin.require_auth();
+
+        let Some(pending_admin) = read_pending_admin(&env) else {
+            panic_with_error!(env, ContractError::NoPendingAdmin);
+        };
+        remove_pending_admin(&env);
+        TokenEvents::admin_transfer_cancelled(&env, pending_admin);
+    }
+
+    fn get_pending_admin(env: Env) -> Option<Address> {
+        read_pending_admin(&env)
     }
 }
 
@@ -0,0 +1,47 @@
+use soroban_sdk::{contractevent, Address, Env};
+
+pub struct TokenEvents {}
+
+impl TokenEvents {
+    /// Emitted when a transfer of the admin is proposed
+    ///
+    /// - topics - `["admin_transfer_proposed", new_admin: Address]`
+    /// - data - ()
+    pub fn admin_transfer_proposed(e: &Env, new_admin: Address) {
+        AdminTransferProposed { new_admin }.publish(e);
+    }
+
+    /// Emitted when a pending transfer of the admin is cancelled
+    ///
+    /// - topics - `["admin_transfer_cancelled", pending_admin: Address]`
+    /// - data - ()
+    pub fn admin_transfer_cancelled(e: &Env, pending_admin: Address) {
+        AdminTransferCancelled { pending_admin }.publish(e);
+    }
+
+    /// Emitted when the proposed admin accepts the transfer
+    ///
+    /// - topics - `["admin_transferred", new_admin: Address]`
+    /// - data - ()
+    pub fn admin_transferred(e: &Env, new_admin: Address) {
+        AdminTransferred { new_admin }.publish(e);
+    }
+}
+
+#[contractevent(data_format = "single-value")]
+struct AdminTransferProposed {
+    #[topic]
+    new_admin: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+struct AdminTransferCancelled {
+    #[topic]
+    pending_admin: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+struct AdminTransferred {
+    #[topic]
+    new_admin: Address,
+}
@@ -3,6 +3,7 @@
 mod admin;
 mod balance;
 mod contract;
+mod events;
 mod storage;
 mod types;
 mod votes;
@@ -8,6 +8,7 @@ pub enum DataKey {
     Balance(Address),
     TotalSupply,
     Addresses,
+    PendingAdmin,
 }
 
 #[contracterror]
@@ -20,6 +21,7 @@ pub enum ContractError {
     ZeroUserCount = 5,
     InconsistentBalancesRounds = 6,
     ReachedMaxAccountsCount = 7,
+    NoPendingAdmin = 8,
 }
 
 #[contracterror]
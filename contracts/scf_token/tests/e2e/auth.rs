@@ -1,5 +1,5 @@
 use soroban_sdk::testutils::{Address as AddressTrait, MockAuth, MockAuthInvoke};
-use soroban_sdk::{Address, Env, IntoVal};
+use soroban_sdk::{Address, Env, Error, IntoVal};
 
 use crate::e2e::common::contract_utils::{
     bump_round, deploy_and_setup, governance, jump, set_nqg_results, Deployment,
@@ -72,6 +72,31 @@ fn transfer_admin() {
         },
     }]);
     client.transfer_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    // Only the new admin can accept the transfer
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "accept_admin",
+            args: ().into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_accept_admin().is_err());
+
+    env.mock_auths(&[MockAuth {
+        address: &new_admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "accept_admin",
+            args: ().into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.accept_admin();
+    assert_eq!(client.get_pending_admin(), None);
 
     authorized_bump_round(&env, &governance_client);
 
@@ -103,3 +128,27 @@ fn transfer_admin() {
     }]);
     client.update_balance(&address);
 }
+
+#[test]
+fn cancel_admin_transfer() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    let Deployment { client, .. } = deploy_and_setup(&env, &admin);
+
+    env.mock_all_auths();
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(Error::from_contract_error(8)))
+    );
+
+    client.transfer_admin(&new_admin);
+    client.cancel_admin_transfer();
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(Error::from_contract_error(8)))
+    );
+}
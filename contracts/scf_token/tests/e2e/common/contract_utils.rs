@@ -23,7 +23,11 @@ pub fn deploy_contract<'a>(
 }
 
 pub fn deploy_scf_contract<'a>(env: &Env, admin: &Address) -> governance::Client<'a> {
+    // uploading the governance wasm alone exceeds the default test budget; calls into it
+    // still run under the default budget
+    env.cost_estimate().budget().reset_unlimited();
     let governance_address = env.register(governance::WASM, ());
+    env.cost_estimate().budget().reset_default();
     let governance_client = governance::Client::new(env, &governance_address);
 
     governance_client.initialize(admin, &25);
@@ -68,7 +72,7 @@ pub fn set_nqg_results(
     let mut result = governance_client
         .try_get_neuron_result(
             &soroban_sdk::String::from_str(env, "0"),
-            &soroban_sdk::String::from_str(env, "0"),
+            &soroban_sdk::String::from_str(env, "Layer1"),
         )
         .unwrap_or_else(|_| {
             let mut map = Map::new(env);
@@ -80,7 +84,7 @@ pub fn set_nqg_results(
 
     governance_client.set_neuron_result(
         &soroban_sdk::String::from_str(env, "0"),
-        &soroban_sdk::String::from_str(env, "0"),
+        &soroban_sdk::String::from_str(env, "Layer1"),
         &result,
     );
 
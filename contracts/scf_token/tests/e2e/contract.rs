@@ -42,7 +42,7 @@ fn updating_balances() {
 
     governance_client.set_neuron_result(
         &String::from_str(&env, "0"),
-        &String::from_str(&env, "0"),
+        &String::from_str(&env, "Layer1"),
         &result,
     );
 
@@ -1,5 +1,4 @@
-use governance::types::Vote;
-use neurons::Submission;
+use governance::types::{Submission, SubmissionCategory, Vote};
 use serde_json::{Map, Value};
 use soroban_sdk::{
     map, vec, Env, Map as SorobanMap, String as SorobanString, Vec as SorobanVec, I256,
@@ -20,29 +19,26 @@ fn i256_from_json_value(env: &Env, value: &Value) -> I256 {
         value.as_str().unwrap().to_string().parse::<i128>().unwrap(),
     )
 }
-pub fn submissions(env: &Env) -> SorobanVec<(SorobanString, SorobanString)> {
+pub fn submissions(env: &Env) -> SorobanVec<Submission> {
     let submissions_raw = fs::read_to_string("../neurons/data/submissions.json").unwrap();
-    let submissions: Vec<Submission> = serde_json::from_str(submissions_raw.as_str()).unwrap();
-    let mut submissions_soroban: SorobanVec<(SorobanString, SorobanString)> = vec![&env];
+    let submissions: Vec<neurons::Submission> =
+        serde_json::from_str(submissions_raw.as_str()).unwrap();
+    let mut submissions_soroban: SorobanVec<Submission> = vec![&env];
     submissions.iter().for_each(|s| {
-        submissions_soroban.push_back(match s.category {
-            neurons::SubmissionCategory::Applications => (
-                SorobanString::from_str(&env, &s.name),
-                SorobanString::from_str(&env, "Applications"),
-            ),
-            neurons::SubmissionCategory::FinancialProtocols => (
-                SorobanString::from_str(&env, &s.name),
-                SorobanString::from_str(&env, "FinancialProtocols"),
-            ),
-            neurons::SubmissionCategory::InfrastructureAndServices => (
-                SorobanString::from_str(&env, &s.name),
-                SorobanString::from_str(&env, "InfrastructureAndServices"),
-            ),
-            neurons::SubmissionCategory::DeveloperTooling => (
-                SorobanString::from_str(&env, &s.name),
-                SorobanString::from_str(&env, "DeveloperTooling"),
-            ),
-        });
+        let category = match s.category {
+            neurons::SubmissionCategory::Applications => SubmissionCategory::Applications,
+            neurons::SubmissionCategory::FinancialProtocols => {
+                SubmissionCategory::FinancialProtocols
+            }
+            neurons::SubmissionCategory::InfrastructureAndServices => {
+                SubmissionCategory::InfrastructureAndServices
+            }
+            neurons::SubmissionCategory::DeveloperTooling => SubmissionCategory::DeveloperTooling,
+        };
+        submissions_soroban.push_back(Submission::new(
+            SorobanString::from_str(&env, &s.name),
+            category,
+        ));
     });
     submissions_soroban
 }
@@ -1,5 +1,5 @@
 use data_generator::{normalized_votes, reputation, submissions, trust, voting_history};
-use governance::types::Vote;
+use governance::types::{Submission, Vote};
 use offchain::manual_tally;
 use soroban_sdk::{Env, Map as SorobanMap, String as SorobanString, Vec as SorobanVec, I256};
 mod data_generator;
@@ -9,7 +9,7 @@ fn main() {
     let env = Env::default();
     env.cost_estimate().budget().reset_unlimited();
 
-    let submissions: SorobanVec<(SorobanString, SorobanString)> = submissions(&env);
+    let submissions: SorobanVec<Submission> = submissions(&env);
     let normalized_votes: SorobanMap<SorobanString, SorobanMap<SorobanString, Vote>> =
         normalized_votes(&env);
     let trust_graph_neuron_result: SorobanMap<SorobanString, I256> = trust(&env);
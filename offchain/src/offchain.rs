@@ -1,6 +1,9 @@
 use std::fs;
 
-use governance::{types::Vote, LayerAggregator, VotingSystem, VotingSystemClient};
+use governance::{
+    types::{Submission, Vote},
+    LayerAggregator, VotingSystem, VotingSystemClient,
+};
 use serde_json::{Map, Number, Value};
 use soroban_sdk::testutils::Address as AddressTrait;
 use soroban_sdk::{
@@ -52,7 +55,7 @@ pub fn setup_layers(env: &Env, contract_client: &VotingSystemClient<'_>) {
 
 pub fn manual_tally(
     env: &Env,
-    submissions: SorobanVec<(SorobanString, SorobanString)>,
+    submissions: SorobanVec<Submission>,
     normalized_votes: SorobanMap<SorobanString, SorobanMap<SorobanString, Vote>>,
     trust_graph_neuron_result: SorobanMap<SorobanString, I256>,
     assigned_reputation_neuron_result: SorobanMap<SorobanString, I256>,
@@ -73,31 +76,32 @@ pub fn manual_tally(
     // set trust graph neuron results
     contract_client.set_neuron_result(
         &SorobanString::from_str(&env, "0"),
-        &SorobanString::from_str(&env, "0"),
+        &SorobanString::from_str(&env, "TrustGraph"),
         &trust_graph_neuron_result,
     );
 
     // set assigned reputation neuron results
     contract_client.set_neuron_result(
         &SorobanString::from_str(&env, "0"),
-        &SorobanString::from_str(&env, "1"),
+        &SorobanString::from_str(&env, "AssignedReputation"),
         &assigned_reputation_neuron_result,
     );
 
     // set prior voting history neuron results
     contract_client.set_neuron_result(
         &SorobanString::from_str(&env, "1"),
-        &SorobanString::from_str(&env, "0"),
+        &SorobanString::from_str(&env, "PriorVotingHistory"),
         &prior_voting_history_neuron_result,
     );
 
     contract_client.calculate_voting_powers();
 
     // tally & write result to file
+    contract_client.tally_round(&0, &u32::MAX);
     let mut results_map: Map<String, Value> = Map::new();
-    for (submission_id, _votes) in normalized_votes {
+    for (submission_id, result) in contract_client.get_tally_results(ROUND) {
         let submission_id_string = submission_id.to_string();
-        let result: i128 = match contract_client.tally_submission(&submission_id).to_i128() {
+        let result: i128 = match result.score.to_i128() {
             Some(result) => result,
             None => panic!("i256 result of [{submission_id_string}] overflow i128"),
         };